@@ -0,0 +1,49 @@
+//! Runtime API definition for the Proportional Asset pallet.
+//!
+//! Allows RPCs and other off-chain consumers to query the ownership and the offers of an asset.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_proportional_asset::{AssetInfo, MetaData};
+
+sp_api::decl_runtime_apis! {
+	pub trait ProportionalAssetApi<AccountId, Identifier, Balance> where
+		AccountId: Codec,
+		Identifier: Codec,
+		Balance: Codec,
+	{
+		/// All the owners of an asset with their metadata, sorted by account id.
+		fn owners_of(id: Identifier) -> Vec<(AccountId, MetaData<Balance>)>;
+
+		/// A page of the owners of an asset starting after `start_key`, with the cursor
+		/// of the next page.
+		fn owners_of_paged(
+			id: Identifier,
+			start_key: Option<AccountId>,
+			limit: u32,
+		) -> (Vec<(AccountId, MetaData<Balance>)>, Option<AccountId>);
+
+		/// The main owner, owner count, supply and frozen state of an asset, if it exists.
+		fn asset_info(id: Identifier) -> Option<AssetInfo<AccountId>>;
+
+		/// The amount `buyer` pays for shares offered by `from`, if they can be bought.
+		fn quote_buy(
+			id: Identifier,
+			buyer: AccountId,
+			from: AccountId,
+			shares: u64,
+		) -> Option<Balance>;
+
+		/// The shares an account offers or orders in an asset and their lowest price, if any.
+		fn active_offer(id: Identifier, who: AccountId) -> Option<(u64, Balance)>;
+
+		/// The total shares offered or ordered for an asset and the lowest price among them.
+		fn market_depth(id: Identifier) -> (u64, Option<Balance>);
+
+		/// Whether an account holds a majority of an asset without being its main owner.
+		fn can_claim_ownership(id: Identifier, who: AccountId) -> bool;
+	}
+}
@@ -0,0 +1,840 @@
+//! Benchmarking setup for pallet-proportional-asset
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as ProportionalAsset;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use sp_runtime::traits::Saturating;
+
+const SEED: u32 = 0;
+
+fn fund<T: Config>(who: &T::AccountId) {
+	let balance = T::Currency::minimum_balance()
+		.saturating_mul(1_000_000u32.into())
+		.saturating_add(T::AssetDeposit::get());
+	T::Currency::make_free_balance_be(who, balance);
+}
+
+fn create_asset<T: Config>(owner: &T::AccountId) -> Identifier<T> {
+	let data: Vec<u8> = (0..T::MaxDataLength::get()).map(|_| 1u8).collect();
+	let id = ProportionalAsset::<T>::next_asset_id(owner, &data);
+
+	assert!(ProportionalAsset::<T>::create_proportional_asset(
+		RawOrigin::Signed(owner.clone()).into(),
+		data,
+		1u32.into(),
+		None,
+		None
+	)
+	.is_ok());
+
+	id
+}
+
+// The amount of owners holding a quarter of the supply of an asset,
+// leaving room in the holders cap for the accounts taking part in a benchmark.
+fn max_owners<T: Config>() -> u32 {
+	let holders = T::MaxHolders::get().saturating_sub(3) as u64;
+	(ProportionalAsset::<T>::total_units() / 4 / T::MinHolding::get().max(1)).min(holders) as u32
+}
+
+// Spreads a quarter of the supply of the asset to other owners.
+fn populate_owners<T: Config>(owner: &T::AccountId, id: &Identifier<T>) {
+	for index in 0..max_owners::<T>() {
+		let recipient: T::AccountId = account("owner", index, SEED);
+
+		assert!(ProportionalAsset::<T>::transfer_shares_to_account(
+			RawOrigin::Signed(owner.clone()).into(),
+			*id,
+			T::MinHolding::get().max(1),
+			recipient
+		)
+		.is_ok());
+	}
+}
+
+benchmarks! {
+	create_proportional_asset {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let data: Vec<u8> = (0..T::MaxDataLength::get()).map(|_| 1u8).collect();
+		let id = ProportionalAsset::<T>::next_asset_id(&caller, &data);
+		let name: Vec<u8> = (0..T::MaxNameLength::get()).map(|_| 1u8).collect();
+		let uri: Vec<u8> = (0..T::MaxUriLength::get()).map(|_| 1u8).collect();
+	}: _(RawOrigin::Signed(caller.clone()), data, 1u32.into(), Some(name), Some(uri))
+	verify {
+		assert_eq!(ProportionalAssetToMainOwner::<T>::get(id), Some(caller));
+		assert!(ProportionalAssetToName::<T>::contains_key(id));
+		assert!(ProportionalAssetToUri::<T>::contains_key(id));
+	}
+
+	offer_shares {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_asset::<T>(&caller);
+		populate_owners::<T>(&caller, &id);
+		let shares = ProportionalAsset::<T>::shares_of(&id, &caller);
+	}: _(RawOrigin::Signed(caller.clone()), id, shares, 1u32.into(), Some(1u32.into()))
+	verify {
+		let metadata = ProportionalAssetToOwnerToMetadata::<T>::get(id, &caller).unwrap();
+		assert_eq!(metadata.offers, Shares(shares));
+	}
+
+	transfer_shares_to_account {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_asset::<T>(&caller);
+		populate_owners::<T>(&caller, &id);
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+	}: _(RawOrigin::Signed(caller), id, 1, recipient.clone())
+	verify {
+		assert_eq!(ProportionalAsset::<T>::shares_of(&id, &recipient), 1);
+	}
+
+	buy_shares {
+		let seller: T::AccountId = account("seller", 0, SEED);
+		fund::<T>(&seller);
+		let id = create_asset::<T>(&seller);
+		populate_owners::<T>(&seller, &id);
+		assert!(ProportionalAsset::<T>::offer_shares(
+			RawOrigin::Signed(seller.clone()).into(),
+			id,
+			1,
+			1u32.into(),
+			Some(1u32.into())
+		)
+		.is_ok());
+
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+
+		// The buyer is the last account of a full allowlist
+		let allowlist: Vec<T::AccountId> = (1..T::MaxAllowlistLength::get())
+			.map(|index| account("buyer", index, SEED))
+			.chain(core::iter::once(caller.clone()))
+			.collect();
+		assert!(ProportionalAsset::<T>::set_offer_allowlist(
+			RawOrigin::Signed(seller.clone()).into(),
+			id,
+			allowlist
+		)
+		.is_ok());
+	}: _(RawOrigin::Signed(caller.clone()), id, 1, 1u32.into(), seller, 1u32.into())
+	verify {
+		assert_eq!(ProportionalAsset::<T>::shares_of(&id, &caller), 1);
+	}
+
+	claim_onwership {
+		let owner: T::AccountId = account("main_owner", 0, SEED);
+		fund::<T>(&owner);
+		let id = create_asset::<T>(&owner);
+		populate_owners::<T>(&owner, &id);
+
+		// The caller takes over the creation deposit
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let majority = ProportionalAsset::<T>::effective_supply(&id) / 2 + 1;
+		assert!(ProportionalAsset::<T>::transfer_shares_to_account(
+			RawOrigin::Signed(owner).into(),
+			id,
+			majority,
+			caller.clone()
+		)
+		.is_ok());
+	}: _(RawOrigin::Signed(caller.clone()), id)
+	verify {
+		assert_eq!(ProportionalAssetToMainOwner::<T>::get(id), Some(caller));
+	}
+
+	burn_shares {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_asset::<T>(&caller);
+		populate_owners::<T>(&caller, &id);
+	}: _(RawOrigin::Signed(caller), id, 1)
+	verify {
+		assert_eq!(ProportionalAssetToBurnedShares::<T>::get(id), 1);
+	}
+
+	destroy_asset {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_asset::<T>(&caller);
+	}: _(RawOrigin::Signed(caller), id)
+	verify {
+		assert!(!ProportionalAssetToMainOwner::<T>::contains_key(id));
+	}
+
+	set_offer_allowlist {
+		let a in 1 .. T::MaxAllowlistLength::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_asset::<T>(&caller);
+		let accounts: Vec<T::AccountId> = (0..a).map(|index| account("buyer", index, SEED)).collect();
+	}: _(RawOrigin::Signed(caller.clone()), id, accounts)
+	verify {
+		let allowlist = ProportionalAssetToOfferAllowlist::<T>::get(id, &caller).unwrap();
+		assert_eq!(allowlist.len() as u32, a);
+	}
+
+	transfer_shares_batch {
+		let b in 1 .. T::MaxBatchSize::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_asset::<T>(&caller);
+		let transfers: Vec<(T::AccountId, u64)> =
+			(0..b).map(|index| (account("recipient", index, SEED), 1)).collect();
+	}: _(RawOrigin::Signed(caller), id, transfers)
+	verify {
+		let recipient: T::AccountId = account("recipient", b - 1, SEED);
+		assert_eq!(ProportionalAsset::<T>::shares_of(&id, &recipient), 1);
+	}
+
+	set_price_floor {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_asset::<T>(&caller);
+	}: _(RawOrigin::Signed(caller), id, 1u32.into())
+	verify {
+		assert_eq!(ProportionalAssetToPriceFloor::<T>::get(id), 1u32.into());
+	}
+
+	increase_offer {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_asset::<T>(&caller);
+		assert!(ProportionalAsset::<T>::offer_shares(
+			RawOrigin::Signed(caller.clone()).into(),
+			id,
+			1,
+			1u32.into(),
+			None
+		)
+		.is_ok());
+	}: _(RawOrigin::Signed(caller.clone()), id, 1)
+	verify {
+		let metadata = ProportionalAssetToOwnerToMetadata::<T>::get(id, &caller).unwrap();
+		assert_eq!(metadata.offers, Shares(2));
+	}
+
+	distribute_dividend {
+		let o in 1 .. max_owners::<T>();
+
+		let owner: T::AccountId = account("main_owner", 0, SEED);
+		fund::<T>(&owner);
+		let id = create_asset::<T>(&owner);
+		for index in 1..o {
+			let recipient: T::AccountId = account("owner", index, SEED);
+			assert!(ProportionalAsset::<T>::transfer_shares_to_account(
+				RawOrigin::Signed(owner.clone()).into(),
+				id,
+				T::MinHolding::get().max(1),
+				recipient
+			)
+			.is_ok());
+		}
+
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let amount = T::Currency::minimum_balance().saturating_mul(1_000u32.into());
+	}: _(RawOrigin::Signed(caller), id, amount)
+	verify {
+		assert!(T::Currency::free_balance(&owner) > 0u32.into());
+	}
+
+	transfer_main_ownership {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_asset::<T>(&caller);
+
+		// The majority moves to the recipient while the caller keeps the main owner record
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+		fund::<T>(&recipient);
+		assert!(ProportionalAsset::<T>::transfer_shares_to_account(
+			RawOrigin::Signed(caller.clone()).into(),
+			id,
+			ProportionalAsset::<T>::total_units() / 2 + 1,
+			recipient.clone()
+		)
+		.is_ok());
+		ProportionalAsset::<T>::set_main_owner(caller.clone(), &id);
+	}: _(RawOrigin::Signed(caller), id, recipient.clone())
+	verify {
+		assert_eq!(ProportionalAssetToMainOwner::<T>::get(id), Some(recipient));
+	}
+
+	set_frozen {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_asset::<T>(&caller);
+	}: _(RawOrigin::Signed(caller), id, true)
+	verify {
+		assert!(ProportionalAssetToFrozen::<T>::get(id));
+	}
+
+	place_order {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_asset::<T>(&caller);
+	}: _(RawOrigin::Signed(caller.clone()), id, 1, 1u32.into())
+	verify {
+		assert_eq!(ProportionalAsset::<T>::ordered_shares(&id, &caller), 1);
+	}
+
+	cancel_order {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_asset::<T>(&caller);
+		let order_id = NextOrderId::<T>::get();
+		assert!(ProportionalAsset::<T>::place_order(
+			RawOrigin::Signed(caller.clone()).into(),
+			id,
+			1,
+			1u32.into()
+		)
+		.is_ok());
+	}: _(RawOrigin::Signed(caller.clone()), id, order_id)
+	verify {
+		assert!(!Orders::<T>::contains_key(id, order_id));
+	}
+
+	fill_order {
+		let seller: T::AccountId = account("seller", 0, SEED);
+		fund::<T>(&seller);
+		let id = create_asset::<T>(&seller);
+		populate_owners::<T>(&seller, &id);
+		let order_id = NextOrderId::<T>::get();
+		assert!(ProportionalAsset::<T>::place_order(
+			RawOrigin::Signed(seller.clone()).into(),
+			id,
+			2,
+			1u32.into()
+		)
+		.is_ok());
+
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+	}: _(RawOrigin::Signed(caller.clone()), id, order_id, 1)
+	verify {
+		assert_eq!(ProportionalAsset::<T>::shares_of(&id, &caller), 1);
+	}
+
+	lock_shares {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_asset::<T>(&caller);
+		assert!(ProportionalAsset::<T>::lock_shares(
+			RawOrigin::Signed(caller.clone()).into(),
+			id,
+			1,
+			1u32.into()
+		)
+		.is_ok());
+	}: _(RawOrigin::Signed(caller.clone()), id, 1, 2u32.into())
+	verify {
+		assert_eq!(ProportionalAsset::<T>::locked_shares(&id, &caller), 2);
+	}
+
+	unlock_shares {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_asset::<T>(&caller);
+		assert!(ProportionalAsset::<T>::lock_shares(
+			RawOrigin::Signed(caller.clone()).into(),
+			id,
+			1,
+			1u32.into()
+		)
+		.is_ok());
+		frame_system::Pallet::<T>::set_block_number(2u32.into());
+	}: _(RawOrigin::Signed(caller.clone()), id)
+	verify {
+		assert_eq!(ProportionalAsset::<T>::locked_shares(&id, &caller), 0);
+	}
+
+	buyback_offered {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_asset::<T>(&caller);
+		populate_owners::<T>(&caller, &id);
+
+		let seller: T::AccountId = account("seller", 0, SEED);
+		let seller_shares = T::MinHolding::get().max(1).saturating_add(1);
+		assert!(ProportionalAsset::<T>::transfer_shares_to_account(
+			RawOrigin::Signed(caller.clone()).into(),
+			id,
+			seller_shares,
+			seller.clone()
+		)
+		.is_ok());
+		assert!(ProportionalAsset::<T>::offer_shares(
+			RawOrigin::Signed(seller.clone()).into(),
+			id,
+			1,
+			1u32.into(),
+			Some(1u32.into())
+		)
+		.is_ok());
+		assert!(ProportionalAsset::<T>::set_offer_allowlist(
+			RawOrigin::Signed(seller.clone()).into(),
+			id,
+			core::iter::once(caller.clone()).collect()
+		)
+		.is_ok());
+	}: _(RawOrigin::Signed(caller.clone()), id, seller.clone(), 1, 1u32.into())
+	verify {
+		assert_eq!(ProportionalAsset::<T>::shares_of(&id, &seller), seller_shares - 1);
+	}
+
+	split_asset {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_asset::<T>(&caller);
+		let data_a: Vec<u8> = (0..T::MaxDataLength::get()).map(|_| 2u8).collect();
+		let data_b: Vec<u8> = (0..T::MaxDataLength::get()).map(|_| 3u8).collect();
+		let id_a = ProportionalAsset::<T>::next_asset_id(&caller, &data_a);
+	}: _(RawOrigin::Signed(caller.clone()), id, data_a, data_b, Permill::from_percent(50))
+	verify {
+		assert_eq!(ProportionalAsset::<T>::shares_of(&id_a, &caller), ProportionalAsset::<T>::total_units());
+	}
+
+	merge_assets {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id_a = create_asset::<T>(&caller);
+		let data_b: Vec<u8> = (0..T::MaxDataLength::get()).map(|_| 2u8).collect();
+		let id_b = ProportionalAsset::<T>::next_asset_id(&caller, &data_b);
+		assert!(ProportionalAsset::<T>::create_proportional_asset(
+			RawOrigin::Signed(caller.clone()).into(),
+			data_b,
+			1u32.into(),
+			None, None)
+		.is_ok());
+		let merged_data: Vec<u8> = (0..T::MaxDataLength::get()).map(|_| 3u8).collect();
+		let merged_id = ProportionalAsset::<T>::next_asset_id(&caller, &merged_data);
+	}: _(RawOrigin::Signed(caller.clone()), id_a, id_b, merged_data)
+	verify {
+		assert_eq!(ProportionalAsset::<T>::shares_of(&merged_id, &caller), ProportionalAsset::<T>::total_units());
+	}
+
+	create_proposal {
+		let o in 0 .. max_owners::<T>();
+
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_asset::<T>(&caller);
+		for index in 0..o {
+			let recipient: T::AccountId = account("owner", index, SEED);
+			assert!(ProportionalAsset::<T>::transfer_shares_to_account(
+				RawOrigin::Signed(caller.clone()).into(),
+				id,
+				T::MinHolding::get().max(1),
+				recipient
+			)
+			.is_ok());
+		}
+		let proposal_id = NextProposalId::<T>::get();
+	}: _(RawOrigin::Signed(caller.clone()), id, T::Hashing::hash(b"proposal"))
+	verify {
+		assert!(ProposalSnapshots::<T>::contains_key((id, proposal_id, caller)));
+	}
+
+	vote {
+		let v in 0 .. max_owners::<T>();
+
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_asset::<T>(&caller);
+		populate_owners::<T>(&caller, &id);
+		let proposal_id = NextProposalId::<T>::get();
+		assert!(ProportionalAsset::<T>::create_proposal(
+			RawOrigin::Signed(caller.clone()).into(),
+			id,
+			T::Hashing::hash(b"proposal")
+		)
+		.is_ok());
+
+		// The votes of the other owners are cleared once the proposal passes
+		for index in 0..v {
+			let voter: T::AccountId = account("owner", index, SEED);
+			assert!(ProportionalAsset::<T>::vote(
+				RawOrigin::Signed(voter).into(),
+				id,
+				proposal_id,
+				true
+			)
+			.is_ok());
+		}
+	}: _(RawOrigin::Signed(caller), id, proposal_id, true)
+	verify {
+		assert!(!Proposals::<T>::contains_key(id, proposal_id));
+	}
+
+	make_bid {
+		let seller: T::AccountId = account("seller", 0, SEED);
+		fund::<T>(&seller);
+		let id = create_asset::<T>(&seller);
+
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+	}: _(RawOrigin::Signed(caller.clone()), id, seller.clone(), 1, 1u32.into())
+	verify {
+		assert!(Bids::<T>::contains_key((id, &seller, &caller)));
+	}
+
+	accept_bid {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_asset::<T>(&caller);
+
+		let bidder: T::AccountId = account("bidder", 0, SEED);
+		fund::<T>(&bidder);
+		let shares = T::MinHolding::get().max(1);
+		assert!(ProportionalAsset::<T>::make_bid(
+			RawOrigin::Signed(bidder.clone()).into(),
+			id,
+			caller.clone(),
+			shares,
+			1u32.into()
+		)
+		.is_ok());
+	}: _(RawOrigin::Signed(caller), id, bidder.clone())
+	verify {
+		assert_eq!(ProportionalAsset::<T>::shares_of(&id, &bidder), shares);
+	}
+
+	cancel_bid {
+		let seller: T::AccountId = account("seller", 0, SEED);
+		fund::<T>(&seller);
+		let id = create_asset::<T>(&seller);
+
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		assert!(ProportionalAsset::<T>::make_bid(
+			RawOrigin::Signed(caller.clone()).into(),
+			id,
+			seller.clone(),
+			1,
+			1u32.into()
+		)
+		.is_ok());
+	}: _(RawOrigin::Signed(caller.clone()), id, seller.clone())
+	verify {
+		assert!(!Bids::<T>::contains_key((id, &seller, &caller)));
+	}
+
+	set_status {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_asset::<T>(&caller);
+	}: _(RawOrigin::Signed(caller), id, AssetStatus::UnderContract)
+	verify {
+		assert_eq!(ProportionalAssetToStatus::<T>::get(id), AssetStatus::UnderContract);
+	}
+
+	approve_shares {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_asset::<T>(&caller);
+		let spender: T::AccountId = account("spender", 0, SEED);
+	}: _(RawOrigin::Signed(caller.clone()), id, spender.clone(), 1)
+	verify {
+		assert_eq!(ProportionalAssetAllowance::<T>::get((id, &caller, &spender)), 1);
+	}
+
+	transfer_shares_from {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		fund::<T>(&owner);
+		let id = create_asset::<T>(&owner);
+
+		let caller: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+		let shares = T::MinHolding::get().max(1);
+		assert!(ProportionalAsset::<T>::approve_shares(
+			RawOrigin::Signed(owner.clone()).into(),
+			id,
+			caller.clone(),
+			shares
+		)
+		.is_ok());
+	}: _(RawOrigin::Signed(caller.clone()), id, owner.clone(), recipient.clone(), shares)
+	verify {
+		assert_eq!(ProportionalAsset::<T>::shares_of(&id, &recipient), shares);
+		assert_eq!(ProportionalAssetAllowance::<T>::get((id, &owner, &caller)), 0);
+	}
+
+	force_destroy_asset {
+		let owner: T::AccountId = account("main_owner", 0, SEED);
+		fund::<T>(&owner);
+		let id = create_asset::<T>(&owner);
+		populate_owners::<T>(&owner, &id);
+	}: _(RawOrigin::Root, id)
+	verify {
+		assert!(!ProportionalAsset::<T>::asset_exists(&id));
+	}
+
+	set_metadata_uri {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_asset::<T>(&caller);
+		let uri: Vec<u8> = (0..T::MaxUriLength::get()).map(|_| 1u8).collect();
+	}: _(RawOrigin::Signed(caller), id, uri)
+	verify {
+		assert!(ProportionalAssetToUri::<T>::contains_key(id));
+	}
+
+	recover_main_owner {
+		let owner: T::AccountId = account("main_owner", 0, SEED);
+		fund::<T>(&owner);
+		let id = create_asset::<T>(&owner);
+		populate_owners::<T>(&owner, &id);
+
+		// Handing a majority over vacates the main owner slot
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let majority = ProportionalAsset::<T>::effective_supply(&id) / 2 + 1;
+		assert!(ProportionalAsset::<T>::transfer_shares_to_account(
+			RawOrigin::Signed(owner).into(),
+			id,
+			majority,
+			caller.clone()
+		)
+		.is_ok());
+	}: _(RawOrigin::Signed(caller.clone()), id)
+	verify {
+		assert_eq!(ProportionalAssetToMainOwner::<T>::get(id), Some(caller));
+	}
+
+	register_swap_intent {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_asset::<T>(&caller);
+		let counterparty: T::AccountId = account("counterparty", 0, SEED);
+	}: _(RawOrigin::Signed(caller.clone()), counterparty.clone(), id, 1, id, 1)
+	verify {
+		assert!(SwapIntents::<T>::contains_key(&caller, &counterparty));
+	}
+
+	cancel_swap_intent {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_asset::<T>(&caller);
+		let counterparty: T::AccountId = account("counterparty", 0, SEED);
+		assert!(ProportionalAsset::<T>::register_swap_intent(
+			RawOrigin::Signed(caller.clone()).into(),
+			counterparty.clone(),
+			id,
+			1,
+			id,
+			1
+		)
+		.is_ok());
+	}: _(RawOrigin::Signed(caller.clone()), counterparty.clone())
+	verify {
+		assert!(!SwapIntents::<T>::contains_key(&caller, &counterparty));
+	}
+
+	swap_shares {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id_a = create_asset::<T>(&caller);
+
+		let counterparty: T::AccountId = account("counterparty", 0, SEED);
+		fund::<T>(&counterparty);
+		let data: Vec<u8> = (0..T::MaxDataLength::get()).map(|_| 2u8).collect();
+		let id_b = ProportionalAsset::<T>::next_asset_id(&counterparty, &data);
+		assert!(ProportionalAsset::<T>::create_proportional_asset(
+			RawOrigin::Signed(counterparty.clone()).into(),
+			data,
+			1u32.into(),
+			None,
+			None
+		)
+		.is_ok());
+
+		let shares = T::MinHolding::get().max(1);
+		assert!(ProportionalAsset::<T>::register_swap_intent(
+			RawOrigin::Signed(counterparty.clone()).into(),
+			caller.clone(),
+			id_b,
+			shares,
+			id_a,
+			shares
+		)
+		.is_ok());
+	}: _(RawOrigin::Signed(caller.clone()), id_a, shares, counterparty.clone(), id_b, shares)
+	verify {
+		assert_eq!(ProportionalAsset::<T>::shares_of(&id_a, &counterparty), shares);
+		assert_eq!(ProportionalAsset::<T>::shares_of(&id_b, &caller), shares);
+	}
+
+	offer_shares_to {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_asset::<T>(&caller);
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+	}: _(RawOrigin::Signed(caller.clone()), id, 1, 1u32.into(), buyer.clone())
+	verify {
+		assert!(PrivateOffers::<T>::contains_key((id, &caller), &buyer));
+	}
+
+	buy_private {
+		let seller: T::AccountId = account("seller", 0, SEED);
+		fund::<T>(&seller);
+		let id = create_asset::<T>(&seller);
+
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let shares = T::MinHolding::get().max(1);
+		assert!(ProportionalAsset::<T>::offer_shares_to(
+			RawOrigin::Signed(seller.clone()).into(),
+			id,
+			shares,
+			1u32.into(),
+			caller.clone()
+		)
+		.is_ok());
+	}: _(RawOrigin::Signed(caller.clone()), id, seller)
+	verify {
+		assert_eq!(ProportionalAsset::<T>::shares_of(&id, &caller), shares);
+	}
+
+	transfer_all_shares {
+		let owner: T::AccountId = account("main_owner", 0, SEED);
+		fund::<T>(&owner);
+		let id = create_asset::<T>(&owner);
+
+		let caller: T::AccountId = whitelisted_caller();
+		let shares = T::MinHolding::get().max(1);
+		assert!(ProportionalAsset::<T>::transfer_shares_to_account(
+			RawOrigin::Signed(owner).into(),
+			id,
+			shares,
+			caller.clone()
+		)
+		.is_ok());
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+	}: _(RawOrigin::Signed(caller.clone()), id, recipient.clone())
+	verify {
+		assert_eq!(ProportionalAsset::<T>::shares_of(&id, &recipient), shares);
+		assert!(!ProportionalAssetToOwnerToMetadata::<T>::contains_key(id, &caller));
+	}
+
+	schedule_pause {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_asset::<T>(&caller);
+		let until = frame_system::Pallet::<T>::block_number() + 10u32.into();
+	}: _(RawOrigin::Signed(caller), id, until)
+	verify {
+		assert_eq!(ProportionalAssetToPauseUntil::<T>::get(id), Some(until));
+	}
+
+	reconcile_main_owner {
+		let owner: T::AccountId = account("main_owner", 0, SEED);
+		fund::<T>(&owner);
+		let id = create_asset::<T>(&owner);
+
+		// The majority moves away while the main owner record is left stale
+		let holder: T::AccountId = account("holder", 0, SEED);
+		let majority = ProportionalAsset::<T>::effective_supply(&id) / 2 + 1;
+		assert!(ProportionalAsset::<T>::transfer_shares_to_account(
+			RawOrigin::Signed(owner.clone()).into(),
+			id,
+			majority,
+			holder
+		)
+		.is_ok());
+		ProportionalAsset::<T>::set_main_owner(owner, &id);
+
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), id)
+	verify {
+		assert_eq!(ProportionalAssetToMainOwner::<T>::get(id), None);
+	}
+
+	start_auction {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_asset::<T>(&caller);
+		let ends_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+	}: _(RawOrigin::Signed(caller.clone()), id, 1, 1u32.into(), ends_at)
+	verify {
+		assert_eq!(ProportionalAsset::<T>::auctioned_shares(&id, &caller), 1);
+	}
+
+	place_auction_bid {
+		let seller: T::AccountId = account("seller", 0, SEED);
+		fund::<T>(&seller);
+		let id = create_asset::<T>(&seller);
+		let ends_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		assert!(ProportionalAsset::<T>::start_auction(
+			RawOrigin::Signed(seller).into(),
+			id,
+			1,
+			1u32.into(),
+			ends_at
+		)
+		.is_ok());
+
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+	}: _(RawOrigin::Signed(caller.clone()), id, 2u32.into())
+	verify {
+		assert_eq!(AuctionBids::<T>::get(id, &caller), Some(2u32.into()));
+	}
+
+	settle_auction {
+		let b in 1 .. T::MaxAuctionBidders::get();
+
+		let seller: T::AccountId = account("seller", 0, SEED);
+		fund::<T>(&seller);
+		let id = create_asset::<T>(&seller);
+		let ends_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		assert!(ProportionalAsset::<T>::start_auction(
+			RawOrigin::Signed(seller).into(),
+			id,
+			T::MinHolding::get().max(1),
+			1u32.into(),
+			ends_at
+		)
+		.is_ok());
+
+		// Every bidder outbids the previous one, the last one wins
+		let mut winner = None;
+		for index in 0..b {
+			let bidder: T::AccountId = account("bidder", index, SEED);
+			fund::<T>(&bidder);
+			assert!(ProportionalAsset::<T>::place_auction_bid(
+				RawOrigin::Signed(bidder.clone()).into(),
+				id,
+				(index + 1).into()
+			)
+			.is_ok());
+			winner = Some(bidder);
+		}
+	}: {
+		ProportionalAsset::<T>::settle_auction(id);
+	}
+	verify {
+		assert!(ProportionalAsset::<T>::shares_of(&id, &winner.unwrap()) > 0);
+	}
+
+	force_transfer_shares {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		fund::<T>(&owner);
+		let id = create_asset::<T>(&owner);
+		populate_owners::<T>(&owner, &id);
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+	}: _(RawOrigin::Root, id, owner, recipient.clone(), 1)
+	verify {
+		assert_eq!(ProportionalAsset::<T>::shares_of(&id, &recipient), 1);
+	}
+
+	impl_benchmark_test_suite!(ProportionalAsset, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,121 @@
+//! Implementations of the fungibles traits over the shares of the assets.
+
+use super::*;
+use frame_support::traits::tokens::{
+	fungibles::{Inspect, Mutate, Transfer},
+	DepositConsequence, WithdrawConsequence,
+};
+use sp_runtime::DispatchError;
+
+impl<T: Config> Inspect<T::AccountId> for Pallet<T> {
+	type AssetId = Identifier<T>;
+	type Balance = u64;
+
+	fn total_issuance(asset: Self::AssetId) -> Self::Balance {
+		if Self::asset_exists(&asset) {
+			Self::effective_supply(&asset)
+		} else {
+			0
+		}
+	}
+
+	fn minimum_balance(_asset: Self::AssetId) -> Self::Balance {
+		T::MinHolding::get()
+	}
+
+	fn balance(asset: Self::AssetId, who: &T::AccountId) -> Self::Balance {
+		Self::shares_of(&asset, who)
+	}
+
+	fn reducible_balance(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		_keep_alive: bool,
+	) -> Self::Balance {
+		Self::spendable_shares(&asset, who)
+	}
+
+	fn can_deposit(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+		mint: bool,
+	) -> DepositConsequence {
+		if !Self::asset_exists(&asset) {
+			return DepositConsequence::UnknownAsset;
+		}
+
+		// The supply of an asset is fixed, shares can only be moved between owners
+		if mint
+			|| Self::shares_of(&asset, who).saturating_add(amount) > Self::effective_supply(&asset)
+		{
+			return DepositConsequence::Overflow;
+		}
+
+		if Self::shares_of(&asset, who).saturating_add(amount) < T::MinHolding::get() {
+			return DepositConsequence::BelowMinimum;
+		}
+
+		// A new owner has to fit in the holders cap
+		if !ProportionalAssetToOwnerToMetadata::<T>::contains_key(asset, who)
+			&& Self::owner_count(&asset) >= T::MaxHolders::get()
+		{
+			return DepositConsequence::CannotCreate;
+		}
+
+		DepositConsequence::Success
+	}
+
+	fn can_withdraw(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> WithdrawConsequence<Self::Balance> {
+		if !Self::asset_exists(&asset) {
+			return WithdrawConsequence::UnknownAsset;
+		}
+
+		if ProportionalAssetToFrozen::<T>::get(asset) {
+			return WithdrawConsequence::Frozen;
+		}
+
+		if Self::spendable_shares(&asset, who) < amount {
+			return WithdrawConsequence::NoFunds;
+		}
+
+		WithdrawConsequence::Success
+	}
+}
+
+impl<T: Config> Mutate<T::AccountId> for Pallet<T> {
+	fn mint_into(
+		_asset: Self::AssetId,
+		_who: &T::AccountId,
+		_amount: Self::Balance,
+	) -> DispatchResult {
+		// The supply of an asset is fixed on creation
+		Err(Error::<T>::TotalSupplyExceeded.into())
+	}
+
+	fn burn_from(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError> {
+		Self::do_burn_shares(asset, who.clone(), amount)?;
+		Ok(amount)
+	}
+}
+
+impl<T: Config> Transfer<T::AccountId> for Pallet<T> {
+	fn transfer(
+		asset: Self::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: Self::Balance,
+		_keep_alive: bool,
+	) -> Result<Self::Balance, DispatchError> {
+		Self::do_transfer_shares(asset, source.clone(), dest.clone(), amount)?;
+		Ok(amount)
+	}
+}
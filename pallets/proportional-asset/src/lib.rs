@@ -16,7 +16,7 @@
 //! ### Terminology
 //!
 //! - **Identifier:** A unique id (Hash) representing a specific asset.
-//! - **Main owner:** An account who owns 50% or more from the asset.
+//! - **Main owner:** An account who owns more than 50% of the asset.
 //! - **Owner:** An account which holds any portion of the asset.
 //! - **MetaData:** Data representing per owner representing the shares of the owner,
 //! the available offers and the price that the owner has set.
@@ -28,9 +28,56 @@
 //! General spending/proposal protocol:
 //! - `create_proportional_asset` - Create a proportional asset with 100% ownership for the caller.
 //! - `offer_shares` - Allows an owner of a portion to make offers for an amount of shares.
+//! - `increase_offer` - Adds shares to the existing offer of an owner.
 //! - `buy_shares` - Allows accounts to buy offered shared for the specified price.
 //! - `transfer_shares_to_account` - Transfers shares to an account (For free!)
+//! - `transfer_all_shares` - Transfers all the unlocked shares of the caller to an account.
+//! - `transfer_shares_batch` - Transfers shares to many accounts at once (For free!)
 //! - `claim_ownership` - Claims the main ownership of an asset.
+//! - `recover_main_owner` - Allows a majority owner to fill a vacated main owner slot.
+//! - `burn_shares` - Permanently retires shares, shrinking the supply of the asset.
+//! - `destroy_asset` - Removes an asset that is fully owned by its main owner.
+//! - `force_destroy_asset` - Allows root to destroy an asset whoever holds its shares.
+//! - `force_transfer_shares` - Allows root to transfer shares without the consent of the sender.
+//! - `set_metadata_uri` - Sets the URI of the off-chain documents of an asset.
+//! - `set_offer_allowlist` - Restricts the accounts allowed to buy the offer of an owner.
+//! - `set_price_floor` - Sets the minimum share price that owners can offer.
+//! - `distribute_dividend` - Distributes an amount to all the owners in proportion to their shares.
+//! - `transfer_main_ownership` - Hands the main ownership over to a majority holder.
+//! - `reconcile_main_owner` - Vacates the main owner slot if the main owner lost the majority.
+//! - `set_frozen` - Halts or resumes the trading of an asset.
+//! - `schedule_pause` - Pauses the trading of an asset until a block.
+//! - `lock_shares` - Locks shares of an owner until a block.
+//! - `unlock_shares` - Releases the locked shares once the block has passed.
+//! - `buyback_offered` - Allows the main owner to buy back offered shares at their price.
+//! - `split_asset` - Splits an asset fully owned by its main owner into two assets.
+//! - `merge_assets` - Merges two assets fully owned by their main owner into one asset.
+//! - `set_status` - Sets the lifecycle status of an asset, shares are only bought while listed.
+//! - `approve_shares` - Allows a spender to transfer an amount of the shares of the origin.
+//! - `transfer_shares_from` - Transfers shares of an owner within the allowance of the origin.
+//! - `register_swap_intent` - Agrees to swap shares with a counterparty.
+//! - `cancel_swap_intent` - Withdraws a swap intent.
+//! - `swap_shares` - Swaps shares of two assets with a counterparty that agreed to it, atomically.
+//! - `start_auction` - Auctions shares to the highest bidder until a block.
+//! - `place_auction_bid` - Bids in the auction of an asset, reserving the amount.
+//!
+//! Order book:
+//! - `place_order` - Puts shares up for sale at a price, next to the orders of other owners.
+//! - `cancel_order` - Withdraws an order from the order book.
+//! - `fill_order` - Buys the shares of an order, fully or partially.
+//!
+//! Private offers:
+//! - `offer_shares_to` - Offers shares exclusively to a buyer at a price.
+//! - `buy_private` - Buys the shares offered exclusively to the caller.
+//!
+//! Bids:
+//! - `make_bid` - Reserves the price of shares to be bought from a seller.
+//! - `accept_bid` - Sells shares to a bidder, paid from the reserved price.
+//! - `cancel_bid` - Withdraws a bid, unreserving its price.
+//!
+//! Voting:
+//! - `create_proposal` - Creates a proposal for the owners of an asset.
+//! - `vote` - Votes on a proposal with the shares held, passing it on a majority.
 //!
 //! The Proportional Asset pallet is loosely coupled with Balances.
 
@@ -38,10 +85,23 @@
 
 pub use pallet::*;
 
-use frame_support::{traits::Currency, PalletId};
+use frame_support::{
+	dispatch::{DispatchError, DispatchErrorWithPostInfo, DispatchResult},
+	ensure,
+	traits::{Currency, ReservableCurrency},
+	weights::Pays,
+	BoundedVec, PalletId,
+};
 
-use frame_support::{inherent::Vec, traits::ExistenceRequirement::AllowDeath};
-use sp_runtime::traits::Hash;
+use frame_support::{
+	inherent::Vec,
+	storage::{with_transaction, TransactionOutcome},
+	traits::ExistenceRequirement::AllowDeath,
+};
+use sp_runtime::{
+	traits::{CheckedMul, Hash, One, Zero},
+	Permill, SaturatedConversion,
+};
 
 #[cfg(test)]
 mod mock;
@@ -52,8 +112,24 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+mod impl_fungibles;
+
+pub mod migrations;
+
+pub mod shares;
+pub use shares::Shares;
+
+pub mod traits;
+pub use traits::{OnMainOwnerChanged, ProportionalAssetInspect};
+
+pub mod weights;
+pub use weights::WeightInfo;
+
 const PALLET_ID: PalletId = PalletId(*b"Asset#*!");
 
+/// The maximum amount of owners returned by a page of `owners_of_paged`.
+pub const MAX_OWNERS_PAGE: u32 = 100;
+
 type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
@@ -63,26 +139,154 @@ pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Metadata struct represents data for each proportional ownership.
 	#[derive(
 		Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo, MaxEncodedLen,
 	)]
-	pub struct MetaData {
-		pub offers: u64,
+	pub struct MetaData<Balance> {
+		pub offers: Shares,
+		pub shares: Shares,
+		pub price: Balance,
+	}
+
+	/// Order struct represents shares put up for sale at a price in the order book of an asset.
+	#[derive(
+		Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct Order<AccountId, Balance> {
+		pub seller: AccountId,
+		pub shares: u64,
+		pub price: Balance,
+	}
+
+	/// AssetInfo struct bundles the state of an asset read by frontends.
+	#[derive(
+		Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct AssetInfo<AccountId> {
+		pub main_owner: Option<AccountId>,
+		pub owner_count: u32,
+		pub effective_supply: u64,
+		pub frozen: bool,
+	}
+
+	/// Bid struct represents an offer to buy shares of a seller, with the price reserved.
+	#[derive(
+		Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct Bid<Balance> {
+		pub shares: u64,
+		pub price: Balance,
+	}
+
+	/// Auction struct represents shares of a seller sold to the highest bid once a block passes.
+	#[derive(
+		Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct Auction<AccountId, Balance, BlockNumber> {
+		pub seller: AccountId,
 		pub shares: u64,
-		pub price: u64,
+		pub reserve_price: Balance,
+		pub ends_at: BlockNumber,
+		pub highest_bidder: Option<AccountId>,
+		pub highest_bid: Balance,
+	}
+
+	/// SwapIntent struct represents shares an account is willing to give for shares of another asset.
+	#[derive(
+		Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct SwapIntent<Identifier> {
+		pub give_id: Identifier,
+		pub give_shares: u64,
+		pub take_id: Identifier,
+		pub take_shares: u64,
+	}
+
+	/// Tally struct represents the shares voting for and against a proposal.
+	#[derive(
+		Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct Tally {
+		pub ayes: u64,
+		pub nays: u64,
+	}
+
+	/// AssetStatus is the stage of the lifecycle an asset is in, set by the main owner.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum AssetStatus {
+		Listed,
+		UnderContract,
+		Sold,
+		Delisted,
+	}
+
+	impl Default for AssetStatus {
+		fn default() -> Self {
+			AssetStatus::Listed
+		}
 	}
 
-	/// TOTAL_SUPPLY constant is the divisor of the asset (percentage).
-	pub const TOTAL_SUPPLY: u64 = 100;
+	/// Rounding is how an amount that does not divide evenly is rounded when distributed.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum Rounding {
+		/// Round down, then hand out the leftover units by the largest remainders
+		LargestRemainder,
+		/// Round down, the leftover units are not distributed
+		Down,
+		/// Round up, the distributed units can add up to more than the amount
+		Up,
+		/// Round half to even, also known as banker's rounding
+		HalfEven,
+	}
+
+	impl Default for Rounding {
+		fn default() -> Self {
+			Rounding::LargestRemainder
+		}
+	}
+
+	impl Rounding {
+		/// Divides a numerator by a non zero denominator, rounding the quotient.
+		///
+		/// `LargestRemainder` rounds down here, the leftover is handed out by the caller.
+		pub fn divide(self, numerator: u128, denominator: u128) -> u128 {
+			let quotient = numerator / denominator;
+			let remainder = numerator % denominator;
+			if self.rounds_up(remainder, denominator, quotient % 2 == 1) {
+				quotient.saturating_add(1)
+			} else {
+				quotient
+			}
+		}
+
+		/// Whether a quotient with the given remainder of the denominator is rounded up.
+		pub fn rounds_up(self, remainder: u128, denominator: u128, odd_quotient: bool) -> bool {
+			match self {
+				Rounding::LargestRemainder | Rounding::Down => false,
+				Rounding::Up => remainder > 0,
+				Rounding::HalfEven => {
+					let doubled = remainder.saturating_mul(2);
+					doubled > denominator || (doubled == denominator && odd_quotient)
+				},
+			}
+		}
+	}
 
 	/// Identifier is the Hash representing uniquely an asset.
 	pub type Identifier<T> = <T as frame_system::Config>::Hash;
 
+	/// MetaDataOf is the MetaData of an owner priced in the balance of the runtime.
+	pub type MetaDataOf<T> = MetaData<BalanceOf<T>>;
+
 	/// ProportionalAssetToOwnerToMetadata is the MetaData that each owner has for an asset.
 	#[pallet::storage]
 	pub type ProportionalAssetToOwnerToMetadata<T: Config> = StorageDoubleMap<
@@ -91,7 +295,7 @@ pub mod pallet {
 		Identifier<T>,
 		Blake2_128Concat,
 		T::AccountId,
-		MetaData,
+		MetaDataOf<T>,
 	>;
 
 	/// ProportionalAssetToMainOwner is the main owner of an asset
@@ -99,25 +303,581 @@ pub mod pallet {
 	pub type ProportionalAssetToMainOwner<T: Config> =
 		StorageMap<_, Blake2_128Concat, Identifier<T>, T::AccountId>;
 
+	/// ProportionalAssetToBurnedShares is the amount of shares of an asset retired permanently
+	#[pallet::storage]
+	pub type ProportionalAssetToBurnedShares<T: Config> =
+		StorageMap<_, Blake2_128Concat, Identifier<T>, u64, ValueQuery>;
+
+	/// ProportionalAssetToDeposit is the account that reserved the creation deposit of an asset
+	/// and the amount reserved
+	#[pallet::storage]
+	pub type ProportionalAssetToDeposit<T: Config> =
+		StorageMap<_, Blake2_128Concat, Identifier<T>, (T::AccountId, BalanceOf<T>)>;
+
+	/// ProportionalAssetToOfferExpiry is the block after which the offer of an owner expires
+	#[pallet::storage]
+	pub type ProportionalAssetToOfferExpiry<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		Identifier<T>,
+		Blake2_128Concat,
+		T::AccountId,
+		T::BlockNumber,
+	>;
+
+	/// ProportionalAssetToLastOffer is the block at which an owner last offered shares
+	#[pallet::storage]
+	pub type ProportionalAssetToLastOffer<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		Identifier<T>,
+		Blake2_128Concat,
+		T::AccountId,
+		T::BlockNumber,
+	>;
+
+	/// ProportionalAssetToOfferAllowlist is the accounts allowed to buy the offer of a seller
+	#[pallet::storage]
+	pub type ProportionalAssetToOfferAllowlist<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		Identifier<T>,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<T::AccountId, T::MaxAllowlistLength>,
+	>;
+
+	/// ProportionalAssetToPriceFloor is the minimum share price that the owners of an asset can offer
+	#[pallet::storage]
+	pub type ProportionalAssetToPriceFloor<T: Config> =
+		StorageMap<_, Blake2_128Concat, Identifier<T>, BalanceOf<T>, ValueQuery>;
+
+	/// ProportionalAssetToOwnerCount is the number of accounts holding shares of an asset
+	#[pallet::storage]
+	pub type ProportionalAssetToOwnerCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, Identifier<T>, u32, ValueQuery>;
+
+	/// ProportionalAssetToFrozen is whether the trading of an asset is halted
+	#[pallet::storage]
+	pub type ProportionalAssetToFrozen<T: Config> =
+		StorageMap<_, Blake2_128Concat, Identifier<T>, bool, ValueQuery>;
+
+	/// ProportionalAssetToStatus is the lifecycle status of an asset, shares are only bought while
+	/// it is listed
+	#[pallet::storage]
+	pub type ProportionalAssetToStatus<T: Config> =
+		StorageMap<_, Blake2_128Concat, Identifier<T>, AssetStatus, ValueQuery>;
+
+	/// ProportionalAssetToTradeVolume is the total amount paid for the shares of an asset
+	#[pallet::storage]
+	pub type ProportionalAssetToTradeVolume<T: Config> =
+		StorageMap<_, Blake2_128Concat, Identifier<T>, BalanceOf<T>, ValueQuery>;
+
+	/// ProportionalAssetToName is the human-readable name of an asset
+	#[pallet::storage]
+	pub type ProportionalAssetToName<T: Config> =
+		StorageMap<_, Blake2_128Concat, Identifier<T>, BoundedVec<u8, T::MaxNameLength>>;
+
+	/// ProportionalAssetToUri is the URI of the off-chain documents of an asset
+	#[pallet::storage]
+	pub type ProportionalAssetToUri<T: Config> =
+		StorageMap<_, Blake2_128Concat, Identifier<T>, BoundedVec<u8, T::MaxUriLength>>;
+
+	/// Orders is the order book of an asset by order id
+	#[pallet::storage]
+	pub type Orders<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		Identifier<T>,
+		Blake2_128Concat,
+		u64,
+		Order<T::AccountId, BalanceOf<T>>,
+	>;
+
+	/// NextOrderId is the id of the next order placed
+	#[pallet::storage]
+	pub type NextOrderId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// OrderedShares is the amount of shares an owner has in the order book of an asset
+	#[pallet::storage]
+	pub type OrderedShares<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		Identifier<T>,
+		Blake2_128Concat,
+		T::AccountId,
+		u64,
+		ValueQuery,
+	>;
+
+	/// ProportionalAssetToLocks is the shares of an owner locked until a block
+	#[pallet::storage]
+	pub type ProportionalAssetToLocks<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		Identifier<T>,
+		Blake2_128Concat,
+		T::AccountId,
+		(u64, T::BlockNumber),
+	>;
+
+	/// ProportionalAssetToOwnerReverseIndex is the assets in which an account holds shares
+	#[pallet::storage]
+	pub type ProportionalAssetToOwnerReverseIndex<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, Identifier<T>, ()>;
+
+	/// MainOwnerToAssets is the assets of which an account is the main owner
+	#[pallet::storage]
+	pub type MainOwnerToAssets<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, Identifier<T>, ()>;
+
+	/// Proposals is the hash of each open proposal of an asset by proposal id
+	#[pallet::storage]
+	pub type Proposals<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, Identifier<T>, Blake2_128Concat, u64, T::Hash>;
+
+	/// ProposalTallies is the shares voted for and against each open proposal of an asset
+	#[pallet::storage]
+	pub type ProposalTallies<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		Identifier<T>,
+		Blake2_128Concat,
+		u64,
+		Tally,
+		ValueQuery,
+	>;
+
+	/// ProposalVotes is whether an owner approved an open proposal of an asset
+	#[pallet::storage]
+	pub type ProposalVotes<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, Identifier<T>>,
+			NMapKey<Blake2_128Concat, u64>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		bool,
+	>;
+
+	/// ProposalSnapshots is the shares each owner held when a proposal of an asset was created
+	#[pallet::storage]
+	pub type ProposalSnapshots<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, Identifier<T>>,
+			NMapKey<Blake2_128Concat, u64>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		u64,
+	>;
+
+	/// OpenProposals is the amount of open proposals of an asset
+	#[pallet::storage]
+	pub type OpenProposals<T: Config> =
+		StorageMap<_, Blake2_128Concat, Identifier<T>, u32, ValueQuery>;
+
+	/// NextProposalId is the id of the next proposal created
+	#[pallet::storage]
+	pub type NextProposalId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// Bids is the offers to buy shares of a seller of an asset by bidder
+	#[pallet::storage]
+	pub type Bids<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, Identifier<T>>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		Bid<BalanceOf<T>>,
+	>;
+
+	/// ProportionalAssetAllowance is the shares of an owner a spender is allowed to transfer
+	#[pallet::storage]
+	pub type ProportionalAssetAllowance<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, Identifier<T>>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		u64,
+		ValueQuery,
+	>;
+
+	/// SwapIntents is the swap an account has agreed to with a counterparty
+	#[pallet::storage]
+	pub type SwapIntents<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		SwapIntent<Identifier<T>>,
+	>;
+
+	/// CreatorNonce is the amount of assets an account has created with salted identifiers
+	#[pallet::storage]
+	pub type CreatorNonce<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+	/// PrivateOffers is the shares an owner offers exclusively to a buyer, with their price
+	#[pallet::storage]
+	pub type PrivateOffers<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(Identifier<T>, T::AccountId),
+		Blake2_128Concat,
+		T::AccountId,
+		(u64, BalanceOf<T>),
+	>;
+
+	/// ProportionalAssetToPauseUntil is the block until which the trading of an asset is paused
+	#[pallet::storage]
+	pub type ProportionalAssetToPauseUntil<T: Config> =
+		StorageMap<_, Blake2_128Concat, Identifier<T>, T::BlockNumber>;
+
+	/// Auctions is the running auction of shares of an asset
+	#[pallet::storage]
+	pub type Auctions<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		Identifier<T>,
+		Auction<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+	>;
+
+	/// AuctionBids is the amount each bidder has reserved in the auction of an asset
+	#[pallet::storage]
+	pub type AuctionBids<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		Identifier<T>,
+		Blake2_128Concat,
+		T::AccountId,
+		BalanceOf<T>,
+	>;
+
+	/// AuctionsEndingAt is the assets whose auction is settled at a block
+	#[pallet::storage]
+	pub type AuctionsEndingAt<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Blake2_128Concat, Identifier<T>, ()>;
+
+	/// NextAuctionSettlement is the earliest block whose auctions may still be unsettled
+	#[pallet::storage]
+	pub type NextAuctionSettlement<T: Config> = StorageValue<_, T::BlockNumber>;
+
+	/// ProportionalAssetToPriceHistory is the latest offer prices of an asset, oldest first
+	#[pallet::storage]
+	pub type ProportionalAssetToPriceHistory<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		Identifier<T>,
+		BoundedVec<(T::BlockNumber, BalanceOf<T>), T::MaxPriceHistory>,
+		ValueQuery,
+	>;
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		/// The event configured from the runtime
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 		/// The currency configured from the runtime
-		type Currency: Currency<Self::AccountId>;
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// The total supply of whole shares every asset is divided to
+		#[pallet::constant]
+		type TotalSupply: Get<u64>;
+		/// The maximum length of the data describing an asset
+		#[pallet::constant]
+		type MaxDataLength: Get<u32>;
+		/// The deposit reserved from the creator of an asset
+		#[pallet::constant]
+		type AssetDeposit: Get<BalanceOf<Self>>;
+		/// The maximum amount of accounts allowed to buy the offer of a seller
+		#[pallet::constant]
+		type MaxAllowlistLength: Get<u32>;
+		/// Weight information for the extrinsics of the pallet
+		type WeightInfo: WeightInfo;
+		/// The maximum amount of transfers in a batch
+		#[pallet::constant]
+		type MaxBatchSize: Get<u32>;
+		/// The maximum length of the name of an asset
+		#[pallet::constant]
+		type MaxNameLength: Get<u32>;
+		/// The maximum length of the metadata URI of an asset
+		#[pallet::constant]
+		type MaxUriLength: Get<u32>;
+		/// The maximum amount of accounts holding shares of an asset
+		#[pallet::constant]
+		type MaxHolders: Get<u32>;
+		/// The maximum amount of accounts bidding in the auction of an asset
+		#[pallet::constant]
+		type MaxAuctionBidders: Get<u32>;
+		/// The maximum amount of auctions settled in a block, the rest carry over
+		#[pallet::constant]
+		type MaxAuctionSettlements: Get<u32>;
+		/// The maximum amount of open proposals of an asset
+		#[pallet::constant]
+		type MaxOpenProposals: Get<u32>;
+		/// The minimum amount of shares an account can hold
+		#[pallet::constant]
+		type MinHolding: Get<u64>;
+		/// The minimum amount of shares moved at once, unless the whole holding is moved
+		#[pallet::constant]
+		type MinShareTransfer: Get<u64>;
+		/// The largest portion of the supply of an asset an account can end up holding
+		#[pallet::constant]
+		type MaxHoldingPercent: Get<Permill>;
+		/// Whether the main owner can end up holding more than `MaxHoldingPercent`
+		#[pallet::constant]
+		type MainOwnerExemptFromCap: Get<bool>;
+		/// How the dividends and the prices of split assets are rounded
+		#[pallet::constant]
+		type RoundingPolicy: Get<Rounding>;
+		/// Whether the identifier of an asset is salted with its creator and their nonce,
+		/// so identical data from different creators results to distinct assets
+		#[pallet::constant]
+		type SaltedAssetIds: Get<bool>;
+		/// The digits after the point of a share, the shares are stored in units of
+		/// `10^-ShareDecimals` of a share and every amount of shares passed to a call is in units.
+		///
+		/// A displayed ownership percentage has the same digits, a percentage of 12.34%
+		/// is displayed as 1234 with 2 decimals. Changing it rescales the existing holdings.
+		#[pallet::constant]
+		type ShareDecimals: Get<u32>;
+		/// Whether a transfer clears the offers of the sender instead of clamping them
+		#[pallet::constant]
+		type ClearOffersOnTransfer: Get<bool>;
+		/// The fee taken from the price of the shares bought
+		#[pallet::constant]
+		type MarketplaceFee: Get<Permill>;
+		/// The maximum price of a share unit in an offer, chosen so that the price of
+		/// all the units of an asset fits in the balance
+		#[pallet::constant]
+		type MaxSharePrice: Get<BalanceOf<Self>>;
+		/// The account receiving the marketplace fees
+		type FeeCollector: Get<Self::AccountId>;
+		/// The maximum change of the share price of an owner in a single offer,
+		/// zero allows any change
+		#[pallet::constant]
+		type MaxPriceChange: Get<Permill>;
+		/// The minimum amount of blocks between two offers of an owner, zero allows any
+		#[pallet::constant]
+		type MinReofferInterval: Get<Self::BlockNumber>;
+		/// The handler notified when the main owner of an asset changes
+		type OnMainOwnerChanged: OnMainOwnerChanged<Self::AccountId, Identifier<Self>>;
+		/// The maximum amount of offer prices kept in the history of an asset
+		#[pallet::constant]
+		type MaxPriceHistory: Get<u32>;
+	}
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// The data of each asset with its owners, their shares and share price.
+		pub assets: Vec<(Vec<u8>, Vec<(T::AccountId, u64, BalanceOf<T>)>)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { assets: Vec::new() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			for (data, owners) in &self.assets {
+				// Genesis assets have no creator to salt their identifier with
+				let id = T::Hashing::hash(data);
+
+				assert!(!Pallet::<T>::asset_exists(&id), "Asset already exists in genesis");
+
+				let total_shares =
+					owners.iter().fold(0u64, |total, (_, shares, _)| total.saturating_add(*shares));
+				assert!(
+					total_shares == Pallet::<T>::total_units(),
+					"Shares of a genesis asset must sum up to the total supply in units"
+				);
+
+				for (who, shares, price) in owners {
+					let metadata =
+						MetaData { shares: Shares(*shares), offers: Shares(0), price: *price };
+					Pallet::<T>::set_metadata(&id, who, metadata);
+				}
+
+				// The majority holder, if any, becomes the main owner
+				if let Some((main_owner, _, _)) =
+					owners.iter().find(|(_, shares, _)| Pallet::<T>::has_majority(&id, *shares))
+				{
+					Pallet::<T>::set_main_owner(main_owner.clone(), &id);
+				}
+			}
+		}
 	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		/// A new asset is initialized
-		ProportionalAssetInitialized(Identifier<T>, T::AccountId),
-		/// New shares have been offerred
-		SharesOffered(Identifier<T>, u64),
-		/// Shares have been transferrred
-		SharesTransferred(T::AccountId, T::AccountId, u64),
+		/// A new asset is initialized, carrying its creator, the supply they hold and the share price
+		ProportionalAssetInitialized(Identifier<T>, T::AccountId, u64, BalanceOf<T>),
+		/// New shares have been offerred, carrying the shares offered in total and their price
+		SharesOffered { id: Identifier<T>, seller: T::AccountId, shares: u64, price: BalanceOf<T> },
+		/// Shares of an asset have been transferrred
+		SharesTransferred(Identifier<T>, T::AccountId, T::AccountId, u64),
 		/// The main owner has changed
 		MainOwnerSet(T::AccountId, Identifier<T>),
+		/// Shares have been burned
+		SharesBurned(Identifier<T>, T::AccountId, u64),
+		/// Offered shares have been bought
+		SharesBought {
+			id: Identifier<T>,
+			buyer: T::AccountId,
+			seller: T::AccountId,
+			shares: u64,
+			price_paid: BalanceOf<T>,
+		},
+		/// The main owner no longer holds a majority and the slot has been vacated
+		MainOwnerVacated(Identifier<T>),
+		/// The asset has been destroyed
+		AssetDestroyed(Identifier<T>),
+		/// The accounts allowed to buy the offer of a seller have been set
+		OfferAllowlistSet(Identifier<T>, T::AccountId),
+		/// The minimum share price of the offers has been set
+		PriceFloorSet(Identifier<T>, BalanceOf<T>),
+		/// An amount has been distributed to the owners of the asset
+		DividendDistributed(Identifier<T>, BalanceOf<T>),
+		/// The trading of the asset has been halted
+		AssetFrozen(Identifier<T>),
+		/// The trading of the asset has been resumed
+		AssetUnfrozen(Identifier<T>),
+		/// A trade has been settled, carrying the shares of both parties after the trade
+		TradeSettled {
+			id: Identifier<T>,
+			buyer: T::AccountId,
+			seller: T::AccountId,
+			shares: u64,
+			price: BalanceOf<T>,
+			buyer_shares_after: u64,
+			seller_shares_after: u64,
+		},
+		/// An order has been placed in the order book
+		OrderPlaced {
+			id: Identifier<T>,
+			order_id: u64,
+			seller: T::AccountId,
+			shares: u64,
+			price: BalanceOf<T>,
+		},
+		/// An order has been withdrawn from the order book
+		OrderCancelled { id: Identifier<T>, order_id: u64 },
+		/// Shares of an order have been bought
+		OrderFilled { id: Identifier<T>, order_id: u64, buyer: T::AccountId, shares: u64 },
+		/// Nobody holds shares of the asset anymore
+		AssetEmptied(Identifier<T>),
+		/// Shares have been locked until a block
+		SharesLocked(Identifier<T>, T::AccountId, u64, T::BlockNumber),
+		/// Locked shares have been released
+		SharesUnlocked(Identifier<T>, T::AccountId, u64),
+		/// A marketplace fee has been charged on a purchase
+		FeeCharged(Identifier<T>, BalanceOf<T>),
+		/// An asset has been split into two assets
+		AssetSplit(Identifier<T>, Identifier<T>, Identifier<T>),
+		/// Two assets have been merged into an asset
+		AssetsMerged(Identifier<T>, Identifier<T>, Identifier<T>),
+		/// A proposal has been created for the owners of an asset
+		ProposalCreated { id: Identifier<T>, proposal_id: u64, proposal_hash: T::Hash },
+		/// An owner has voted on a proposal with their shares
+		Voted {
+			id: Identifier<T>,
+			proposal_id: u64,
+			voter: T::AccountId,
+			approve: bool,
+			shares: u64,
+		},
+		/// The shares approving a proposal exceed half of the supply
+		ProposalPassed(Identifier<T>, u64),
+		/// The shares rejecting a proposal leave it without a possible majority
+		ProposalRejected(Identifier<T>, u64),
+		/// A bid has been made and its price reserved
+		BidPlaced {
+			id: Identifier<T>,
+			seller: T::AccountId,
+			bidder: T::AccountId,
+			shares: u64,
+			price: BalanceOf<T>,
+		},
+		/// A bid has been accepted and settled from the reserved price
+		BidAccepted {
+			id: Identifier<T>,
+			seller: T::AccountId,
+			bidder: T::AccountId,
+			shares: u64,
+			price: BalanceOf<T>,
+		},
+		/// A bid has been withdrawn and its price unreserved
+		BidCancelled { id: Identifier<T>, seller: T::AccountId, bidder: T::AccountId },
+		/// The lifecycle status of the asset has been set
+		StatusChanged(Identifier<T>, AssetStatus),
+		/// An owner has allowed a spender to transfer an amount of their shares
+		Approval { id: Identifier<T>, owner: T::AccountId, spender: T::AccountId, amount: u64 },
+		/// The metadata URI of the asset has been set
+		MetadataUriSet(Identifier<T>),
+		/// An account has agreed to swap shares with a counterparty
+		SwapIntentRegistered {
+			who: T::AccountId,
+			counterparty: T::AccountId,
+			intent: SwapIntent<Identifier<T>>,
+		},
+		/// A swap intent has been withdrawn
+		SwapIntentCancelled { who: T::AccountId, counterparty: T::AccountId },
+		/// Shares of two assets have been swapped between two accounts
+		SharesSwapped {
+			who: T::AccountId,
+			counterparty: T::AccountId,
+			id_a: Identifier<T>,
+			shares_a: u64,
+			id_b: Identifier<T>,
+			shares_b: u64,
+		},
+		/// Shares have been offered exclusively to a buyer
+		PrivateOfferMade {
+			id: Identifier<T>,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			shares: u64,
+			price: BalanceOf<T>,
+		},
+		/// The creation deposit of an asset has been reserved from an account
+		DepositReserved(Identifier<T>, T::AccountId, BalanceOf<T>),
+		/// The creation deposit of an asset has been returned to an account
+		DepositRefunded(Identifier<T>, T::AccountId, BalanceOf<T>),
+		/// The trading of the asset is paused until a block
+		TradingPaused(Identifier<T>, T::BlockNumber),
+		/// Shares of an asset are auctioned until a block
+		AuctionStarted {
+			id: Identifier<T>,
+			seller: T::AccountId,
+			shares: u64,
+			reserve_price: BalanceOf<T>,
+			ends_at: T::BlockNumber,
+		},
+		/// A bid has been placed in the auction of an asset, the amount is reserved
+		AuctionBidPlaced { id: Identifier<T>, bidder: T::AccountId, amount: BalanceOf<T> },
+		/// The auction of an asset has ended, the shares went to the winner if any
+		AuctionSettled {
+			id: Identifier<T>,
+			seller: T::AccountId,
+			winner: Option<T::AccountId>,
+			shares: u64,
+			price: BalanceOf<T>,
+		},
+		/// Shares have been transferred by root without the consent of the sender
+		SharesForceTransferred {
+			id: Identifier<T>,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: u64,
+		},
 	}
 
 	#[pallet::error]
@@ -126,12 +886,8 @@ pub mod pallet {
 		AssetDoesNotExist,
 		/// The asset already exists
 		AssetAlreadyExists,
-		/// The offers are invalid
-		InvalidOffers,
 		/// The amount sent is incorrect
 		IncorrectAmount,
-		/// Cannot convert balances
-		ConversionError,
 		/// The shares are incorrect
 		IncorrectSharesSelection,
 		/// The seller selected is incorrect
@@ -146,6 +902,129 @@ pub mod pallet {
 		InvalidAccount,
 		/// The balance is not enough
 		InsufficientBalance,
+		/// The account does not own any shares of the asset
+		NotAnOwner,
+		/// The recipient is the same as the origin
+		CannotTransferToSelf,
+		/// The shares exceed the total supply of the asset
+		TotalSupplyExceeded,
+		/// The data of the asset is empty
+		EmptyAssetData,
+		/// The data of the asset is longer than the maximum length
+		AssetDataTooLong,
+		/// Other owners still hold shares of the asset
+		AssetHasMultipleOwners,
+		/// The offer has expired
+		OfferExpired,
+		/// The buyer is not allowed to buy the offer
+		BuyerNotAllowed,
+		/// The allowlist has more accounts than the maximum
+		AllowlistTooLong,
+		/// The batch has more transfers than the maximum
+		BatchTooLarge,
+		/// The share price is below the floor set by the main owner
+		PriceBelowFloor,
+		/// The share price is above `MaxSharePrice`
+		PriceTooHigh,
+		/// The amount is zero
+		ZeroAmount,
+		/// The trading of the asset is halted
+		AssetFrozen,
+		/// The name of the asset is longer than the maximum length
+		NameTooLong,
+		/// The share price of an offer is zero
+		ZeroPrice,
+		/// The order does not exist
+		OrderNotFound,
+		/// The account is not the seller of the order
+		NotOrderSeller,
+		/// The asset would have more holders than the maximum
+		TooManyHolders,
+		/// The shares held would be below the minimum holding
+		HoldingBelowMinimum,
+		/// The price of the shares is higher than the maximum accepted
+		PriceSlippageExceeded,
+		/// The account has no locked shares
+		NoLockedShares,
+		/// The shares are locked until a later block
+		SharesStillLocked,
+		/// The price of the shares overflows
+		PriceOverflow,
+		/// An asset cannot be merged with itself
+		CannotMergeWithSelf,
+		/// The proposal does not exist
+		ProposalNotFound,
+		/// The account has already voted on the proposal
+		AlreadyVoted,
+		/// The asset has as many open proposals as allowed
+		TooManyProposals,
+		/// The bid does not exist
+		BidNotFound,
+		/// The bidder already has a bid for the shares of the seller
+		BidAlreadyExists,
+		/// The share price changes more than the maximum allowed
+		PriceChangeTooLarge,
+		/// The asset is not listed, its shares cannot be bought
+		AssetNotListed,
+		/// The amount is above the shares the spender is allowed to transfer
+		InsufficientAllowance,
+		/// The amount of shares moved is below the minimum
+		TransferBelowMinimum,
+		/// The metadata URI of the asset is longer than the maximum length
+		UriTooLong,
+		/// The shares held would be above the largest portion of the supply allowed
+		HoldingCapExceeded,
+		/// The asset already has a main owner
+		MainOwnerExists,
+		/// The swap intent does not exist
+		SwapIntentNotFound,
+		/// The swap does not match the intent registered by the counterparty
+		SwapIntentMismatch,
+		/// The offers, orders and private offers together exceed the shares that can be sold
+		OverCommittedOffers,
+		/// The trading of the asset is paused until a later block
+		AssetPaused,
+		/// The owner offered shares too recently to offer again
+		ReofferTooSoon,
+		/// The currency could not be transferred, e.g. it would leave an account below
+		/// the existential deposit
+		CurrencyTransferFailed,
+		/// The asset is already being auctioned
+		AuctionExists,
+		/// The asset is not being auctioned
+		AuctionNotFound,
+		/// The auction has ended or would end before the next block
+		AuctionEnded,
+		/// The bid is below the reserve price or does not outbid the highest bid
+		AuctionBidTooLow,
+		/// The auction has more bidders than the maximum
+		TooManyAuctionBidders,
+		/// The shares of an account would overflow
+		ShareOverflow,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			Self::settle_ended_auctions(now)
+		}
+
+		// This branch of FRAME has no `try_state` hook yet,
+		// the invariants are checked once the runtime upgrades are applied
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::do_try_state()
+		}
+
+		fn integrity_test() {
+			assert!(
+				10u128
+					.checked_pow(T::ShareDecimals::get())
+					.and_then(|scale| u128::from(T::TotalSupply::get()).checked_mul(scale))
+					.map_or(false, |units| units <= u128::from(u64::MAX)),
+				"The total supply in units should fit in u64"
+			);
+		}
 	}
 
 	#[pallet::call]
@@ -153,85 +1032,115 @@ pub mod pallet {
 		/// Create a new proportional asset
 		///
 		/// The Hash of the data passed should not result to an existing asset identifier.
+		/// The data cannot be empty or longer than `MaxDataLength`.
+		/// The name cannot be longer than `MaxNameLength`.
+		/// The metadata URI cannot be longer than `MaxUriLength`.
+		/// The origin should be able to reserve the `AssetDeposit`.
 		///
 		/// A proportional asset gets created successfully and allocated to the main owner
 		/// else the call fails
 		///
 		/// If the call is success, the metadata added for the origin
-		/// are TOTAL_SUPPLY number of shares, 0 offers and the specified share_price
+		/// are `TotalSupply` shares in units, 0 offers and the specified share_price
 		///
 		/// - `data`: The data information about the asset.
 		/// - `share_price`: The share price for the origin's shares
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1, 2))]
+		/// - `name`: The human-readable name of the asset, if any
+		/// - `metadata_uri`: The URI of the off-chain documents of the asset, if any
+		#[pallet::weight(T::WeightInfo::create_proportional_asset())]
 		pub fn create_proportional_asset(
 			origin: OriginFor<T>,
 			data: Vec<u8>,
-			share_price: u64,
+			share_price: BalanceOf<T>,
+			name: Option<Vec<u8>>,
+			metadata_uri: Option<Vec<u8>>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			// get a hash of the data
-			let id = T::Hashing::hash(&data);
-
-			// Check if id exists
-			match ProportionalAssetToOwnerToMetadata::<T>::get(id, who.clone()) {
-				Some(_metadata) => Err(Error::<T>::AssetAlreadyExists)?,
-				None => {
-					let metadata = MetaData { shares: TOTAL_SUPPLY, offers: 0, price: share_price };
-
-					// Create the asset & set the owner
-					// Initialize owner with all the supply
-					// Initialize assets offers to 0
-					ProportionalAssetToOwnerToMetadata::<T>::set(id, &who, Some(metadata));
-
-					// Set the main owner of the asset
-					ProportionalAssetToMainOwner::<T>::set(id, Some(who.clone()));
 
-					Self::deposit_event(Event::ProportionalAssetInitialized(id, who));
+			let id = Self::do_create_asset(who, data, share_price, name)?;
 
-					Ok(())
-				},
+			if let Some(uri) = metadata_uri {
+				Self::do_set_metadata_uri(id, uri)?;
 			}
+
+			Ok(())
 		}
 
 		/// Offers new shares for sale
 		///
-		/// The origin should own at least the amount to be offerred.
+		/// The origin should be an owner of the asset and own at least the amount to be offerred.
+		/// The share price cannot be zero.
+		/// The share price cannot be below the floor set by the main owner.
 		///
 		/// The offers for the metadata of the origin is successfully updated
 		/// else the call fails.
 		///
 		/// If the call is success, the metadata added for the origin
-		/// are updated with the new price and the offers are incremented by
-		/// the shares to be offerred
+		/// are updated with the new price and the offers are set to
+		/// the shares to be offerred, replacing any previous offer.
+		/// Use `increase_offer` to add shares to an existing offer instead.
 		///
 		/// - `id`: The identifier of the asset
 		/// - `shares_to_offer`: The amount of shares to be offerred
 		/// - `share_price`: The price to offer each portion
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2, 1))]
+		/// - `expires_at`: The block after which the offer cannot be bought, if any
+		#[pallet::weight(T::WeightInfo::offer_shares())]
 		pub fn offer_shares(
 			origin: OriginFor<T>,
 			id: Identifier<T>,
 			shares_to_offer: u64,
-			share_price: u64,
+			share_price: BalanceOf<T>,
+			expires_at: Option<T::BlockNumber>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			ensure!(Self::is_owner_of(&who, &id), Error::<T>::NotMainOwner);
+			Self::ensure_asset_exists(&id)?;
 
+			ensure!(shares_to_offer > 0, Error::<T>::ZeroAmount);
+			ensure!(!share_price.is_zero(), Error::<T>::ZeroPrice);
+			ensure!(!ProportionalAssetToFrozen::<T>::get(id), Error::<T>::AssetFrozen);
+
+			// Any owner of a portion of the asset is allowed to offer shares
 			match ProportionalAssetToOwnerToMetadata::<T>::get(id, who.clone()) {
-				None => Err(Error::<T>::InvalidAccount)?,
+				None => Err(Error::<T>::NotAnOwner)?,
 				Some(metadata) => {
-					ensure!(&shares_to_offer.le(&metadata.shares), Error::<T>::InvalidOffers);
+					// The new offer replaces the previous one, the other channels are kept
+					Self::ensure_offers_within_holdings(
+						&id,
+						&who,
+						Self::committed_offers(&id, &who)
+							.saturating_sub(metadata.offers.into())
+							.saturating_add(shares_to_offer),
+					)?;
+					ensure!(
+						share_price.ge(&ProportionalAssetToPriceFloor::<T>::get(id)),
+						Error::<T>::PriceBelowFloor
+					);
+					ensure!(share_price.le(&T::MaxSharePrice::get()), Error::<T>::PriceTooHigh);
+					ensure!(
+						Self::is_price_change_allowed(metadata.price, share_price),
+						Error::<T>::PriceChangeTooLarge
+					);
+
+					let now = Self::ensure_reoffer_allowed(&id, &who)?;
 
 					let new_metadata = MetaData {
 						shares: metadata.shares,
-						offers: shares_to_offer,
+						offers: Shares(shares_to_offer),
 						price: share_price,
 					};
 
-					ProportionalAssetToOwnerToMetadata::<T>::set(id, who, Some(new_metadata));
+					ProportionalAssetToOwnerToMetadata::<T>::set(id, &who, Some(new_metadata));
+					ProportionalAssetToOfferExpiry::<T>::set(id, &who, expires_at);
+					ProportionalAssetToLastOffer::<T>::insert(id, &who, now);
+					Self::record_offer_price(&id, now, share_price);
 
-					Self::deposit_event(Event::SharesOffered(id, share_price));
+					Self::deposit_event(Event::SharesOffered {
+						id,
+						seller: who,
+						shares: shares_to_offer,
+						price: share_price,
+					});
 
 					Ok(())
 				},
@@ -240,7 +1149,8 @@ pub mod pallet {
 
 		/// Transfers shares for free
 		///
-		/// The origin should own the at least the amount to be transferred.
+		/// The asset should exist, the origin should own the at least the amount to be
+		/// transferred and cannot be the recipient.
 		///
 		/// The offers for the metadata of the origin are successfully updated
 		/// else the call fails.
@@ -250,7 +1160,7 @@ pub mod pallet {
 		/// - `id`: The identifier of the asset
 		/// - `amount`: The amount of shares to be transferred
 		/// - `to`: The recipient account
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2, 1))]
+		#[pallet::weight(T::WeightInfo::transfer_shares_to_account())]
 		pub fn transfer_shares_to_account(
 			origin: OriginFor<T>,
 			id: Identifier<T>,
@@ -259,233 +1169,2941 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			match ProportionalAssetToOwnerToMetadata::<T>::get(id, who.clone()) {
-				None => Err(Error::<T>::InvalidAccount)?,
-				Some(origin_metadata) => {
-					ensure!(
-						origin_metadata.shares.ge(&amount),
-						Error::<T>::IncorrectSharesSelection
-					);
+			Self::ensure_asset_exists(&id)?;
 
-					// Decrease origin shares
-					let new_origin_shares = origin_metadata.shares.saturating_sub(amount);
-
-					match ProportionalAssetToOwnerToMetadata::<T>::get(id, to.clone()) {
-						None => {
-							let new_metadata = MetaData { shares: amount, offers: 0, price: 0 };
-
-							ProportionalAssetToOwnerToMetadata::<T>::set(
-								id,
-								to.clone(),
-								Some(new_metadata),
-							);
-						},
-						Some(metadata) => {
-							// Increase to shares
-							let new_to_shares = metadata.shares.saturating_add(amount);
-
-							let new_metadata = MetaData {
-								shares: new_to_shares,
-								offers: metadata.offers, //TODO: Fix offers
-								price: metadata.price,
-							};
-
-							ProportionalAssetToOwnerToMetadata::<T>::set(
-								id,
-								to.clone(),
-								Some(new_metadata),
-							);
-						},
-					}
-
-					// Update the origin metadata
-					let new_origin_metadata = MetaData {
-						shares: new_origin_shares,
-						offers: origin_metadata.offers,
-						price: origin_metadata.price,
-					};
+			Self::do_transfer_shares(id, who, to, amount)
+		}
 
-					ProportionalAssetToOwnerToMetadata::<T>::set(
-						id,
-						who.clone(),
-						Some(new_origin_metadata),
-					);
+		/// Transfer all the shares of the origin to an account
+		///
+		/// The locked shares stay with the origin, the metadata of the origin is removed
+		/// once no shares are left.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `to`: The recipient account
+		#[pallet::weight(T::WeightInfo::transfer_all_shares())]
+		pub fn transfer_all_shares(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			to: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-					Self::deposit_event(Event::SharesTransferred(who, to, amount));
+			Self::ensure_asset_exists(&id)?;
 
-					Ok(())
-				},
-			}
+			ensure!(
+				ProportionalAssetToOwnerToMetadata::<T>::contains_key(id, &who),
+				Error::<T>::NotAnOwner
+			);
+
+			let amount = Self::spendable_shares(&id, &who);
+			Self::do_transfer_shares(id, who, to, amount)
 		}
 
 		/// Buy offerred shares
 		///
-		/// The origin should own have balance more than the amount sent
+		/// The origin should have balance for the price owed, which cannot exceed the amount sent,
 		/// and the seller should own at least the specified shares
 		///
 		/// The shares are transferred to the origin
-		/// and the amount transferred to the seller
+		/// and the price owed for them transferred to the seller,
+		/// any surplus of the amount sent stays with the origin
 		///
+		/// The call is free when the asset is frozen, unlisted or paused.
 		///
 		/// - `id`: The identifier of the asset
 		/// - `shares_to_buy`: The amount of shares to be be purchased
-		/// - `amount`: The amount sent for payment
+		/// - `amount`: The maximum amount to be paid
 		/// - `from`: The seller
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(5, 3))]
+		/// - `max_total_price`: The maximum price the origin accepts to pay for the shares
+		#[pallet::weight(T::WeightInfo::buy_shares())]
 		pub fn buy_shares(
 			origin: OriginFor<T>,
 			id: Identifier<T>,
 			shares_to_buy: u64,
 			amount: BalanceOf<T>,
 			from: T::AccountId,
+			max_total_price: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+			Self::ensure_trading_allowed(&id)?;
+
+			Self::do_buy_shares(id, who, from, shares_to_buy, amount, max_total_price)?;
+
+			Ok(().into())
+		}
+
+		/// Claim main ownership of the asset
+		///
+		/// The origin should own more than 1/2 of the asset, so an evenly split asset
+		/// cannot be claimed by any of the owners. A vacated main owner slot can be claimed too.
+		///
+		/// The origin takes over the creation deposit of the asset, reserving it
+		/// before it is returned to the account that reserved it.
+		///
+		/// The main ownershipt of the asset changes
+		/// else the call fails.
+		///
+		/// - `id`: The identifier of the asset
+		#[pallet::weight(T::WeightInfo::claim_onwership())]
+		pub fn claim_onwership(origin: OriginFor<T>, id: Identifier<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			// Make sure that the origin is not the asset owner
+			ensure!(!Self::is_owner_of(&who, &id), Error::<T>::AlreadyMainOnwer);
+
+			Self::do_take_main_ownership(id, who)
+		}
+
+		/// Recover the vacated main owner slot of the asset
+		///
+		/// The asset should have no main owner and the origin should own more than 1/2
+		/// of the asset. Unlike `claim_onwership`, it never replaces a main owner.
+		///
+		/// The origin takes over the creation deposit of the asset.
+		///
+		/// - `id`: The identifier of the asset
+		#[pallet::weight(T::WeightInfo::recover_main_owner())]
+		pub fn recover_main_owner(origin: OriginFor<T>, id: Identifier<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			ensure!(
+				!ProportionalAssetToMainOwner::<T>::contains_key(id),
+				Error::<T>::MainOwnerExists
+			);
+
+			Self::do_take_main_ownership(id, who)
+		}
+
+		/// Burn shares of the asset
+		///
+		/// The origin should own at least the amount to be burned.
+		///
+		/// The burned shares are retired permanently and the supply of the asset
+		/// shrinks accordingly, else the call fails.
+		///
+		/// Burning the whole supply removes the asset and returns the creation deposit.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `amount`: The amount of shares to be burned
+		#[pallet::weight(T::WeightInfo::burn_shares()
+			.max(T::WeightInfo::force_destroy_asset(1))
+			.saturating_add(Pallet::<T>::proposals_cleanup_weight()))]
+		pub fn burn_shares(origin: OriginFor<T>, id: Identifier<T>, amount: u64) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			Self::do_burn_shares(id, who, amount)
+		}
+
+		/// Destroy an asset
+		///
+		/// The origin should be the main owner and the only owner of the asset.
+		///
+		/// The asset gets removed from storage and the creation deposit is returned
+		/// else the call fails.
+		///
+		/// - `id`: The identifier of the asset
+		#[pallet::weight(T::WeightInfo::destroy_asset()
+			.saturating_add(Pallet::<T>::proposals_cleanup_weight()))]
+		pub fn destroy_asset(origin: OriginFor<T>, id: Identifier<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			ensure!(Self::is_owner_of(&who, &id), Error::<T>::NotMainOwner);
+
+			Self::do_destroy_asset(id, who)
+		}
+
+		/// Destroy an asset whoever holds its shares
+		///
+		/// The origin should be root.
+		///
+		/// The asset gets removed from storage, the bids and the creation deposit are returned.
+		///
+		/// - `id`: The identifier of the asset
+		#[pallet::weight(T::WeightInfo::force_destroy_asset(T::MaxHolders::get())
+			.saturating_add(Pallet::<T>::proposals_cleanup_weight()))]
+		pub fn force_destroy_asset(origin: OriginFor<T>, id: Identifier<T>) -> DispatchResult {
+			ensure_root(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			Self::purge_asset(id);
+
+			Ok(())
+		}
+
+		/// Transfer shares of an asset between accounts without the consent of the sender
+		///
+		/// The origin should be root, e.g. to settle a dispute or an inheritance.
+		///
+		/// The transfer goes through even if the asset is frozen, the shares are locked
+		/// or the amount is below the minimum transfer. The locks and offers of the sender
+		/// shrink to the shares left, the owner count and the main owner are updated accordingly.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `from`: The account the shares are taken from
+		/// - `to`: The recipient account
+		/// - `amount`: The amount of shares to be transferred
+		#[pallet::weight(T::WeightInfo::force_transfer_shares())]
+		pub fn force_transfer_shares(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: u64,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			Self::do_force_transfer_shares(id, from.clone(), to.clone(), amount)?;
+
+			Self::deposit_event(Event::SharesForceTransferred { id, from, to, amount });
+
+			Ok(())
+		}
+
+		/// Set the accounts allowed to buy the offer of the origin
+		///
+		/// The origin should be an owner of the asset.
+		///
+		/// Only the allowlisted accounts can buy the offered shares of the origin,
+		/// an empty list allows any account to buy them.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `accounts`: The accounts allowed to buy the offered shares
+		#[pallet::weight(T::WeightInfo::set_offer_allowlist(accounts.len() as u32))]
+		pub fn set_offer_allowlist(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			accounts: Vec<T::AccountId>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			// // Ensure that the sender is not the seller
-			ensure!(who != from, Error::<T>::IncorrectSeller);
+			Self::ensure_asset_exists(&id)?;
+
+			ensure!(
+				ProportionalAssetToOwnerToMetadata::<T>::contains_key(id, &who),
+				Error::<T>::NotAnOwner
+			);
 
-			// // Ensure that "from" is the owner of the asset
-			ensure!(Self::is_owner_of(&from, &id), Error::<T>::IncorrectSeller);
+			if accounts.is_empty() {
+				ProportionalAssetToOfferAllowlist::<T>::remove(id, &who);
+			} else {
+				let allowlist =
+					BoundedVec::<T::AccountId, T::MaxAllowlistLength>::try_from(accounts)
+						.map_err(|_| Error::<T>::AllowlistTooLong)?;
+				ProportionalAssetToOfferAllowlist::<T>::insert(id, &who, allowlist);
+			}
 
-			match ProportionalAssetToOwnerToMetadata::<T>::get(id, from.clone()) {
-				None => Err(Error::<T>::InvalidAccount)?,
-				Some(from_metadata) => {
-					// make sure that "from" owns more than the specified shares_to_buy
-					ensure!(from_metadata.shares.ge(&shares_to_buy), Error::<T>::IncorrectAmount);
+			Self::deposit_event(Event::OfferAllowlistSet(id, who));
 
-					// Calculate the correct price
-					let price = from_metadata.price.saturating_mul(shares_to_buy);
+			Ok(())
+		}
 
-					let parsed_amount_sent =
-						Self::balance_to_u64_option(amount).ok_or(Error::<T>::ConversionError)?;
+		/// Transfers shares to many accounts at once for free
+		///
+		/// The origin should own at least the sum of all the amounts to be transferred
+		/// and cannot be any of the recipients.
+		///
+		/// Either all the transfers are applied or none of them, the call fails
+		/// before touching storage if the batch is invalid.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `transfers`: The recipient accounts with the amount of shares for each
+		#[pallet::weight(T::WeightInfo::transfer_shares_batch(transfers.len() as u32))]
+		pub fn transfer_shares_batch(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			transfers: Vec<(T::AccountId, u64)>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-					// Ensure that the amount sent is correnct
-					ensure!(parsed_amount_sent.ge(&price), Error::<T>::IncorrectAmount);
+			Self::ensure_asset_exists(&id)?;
 
-					// Make sure that shares_to_buy <= from's shares
-					ensure!(
-						shares_to_buy.le(&from_metadata.shares),
-						Error::<T>::IncorrectSharesSelection
-					);
+			ensure!(transfers.iter().all(|(_, amount)| *amount > 0), Error::<T>::ZeroAmount);
+			ensure!(!ProportionalAssetToFrozen::<T>::get(id), Error::<T>::AssetFrozen);
 
-					// Make sure that shares_to_buy <= offered
-					ensure!(
-						shares_to_buy.le(&from_metadata.offers),
-						Error::<T>::IncorrectSharesSelection
-					);
+			ensure!(transfers.len() <= T::MaxBatchSize::get() as usize, Error::<T>::BatchTooLarge);
 
-					// Decrease for owner of the share
-					let new_from_shares = from_metadata.shares.saturating_sub(shares_to_buy);
+			// Reading and writing the same metadata twice would mint shares
+			ensure!(transfers.iter().all(|(to, _)| to != &who), Error::<T>::CannotTransferToSelf);
 
-					// Update from offers
-					let new_from_offers = from_metadata.offers.saturating_sub(shares_to_buy);
+			ensure!(
+				ProportionalAssetToOwnerToMetadata::<T>::contains_key(id, &who),
+				Error::<T>::InvalidAccount
+			);
 
-					let new_from_metadata = MetaData {
-						shares: new_from_shares,
-						offers: new_from_offers,
-						price: from_metadata.price,
-					};
+			// Validate the whole batch before applying any of the transfers
+			let total_amount = transfers
+				.iter()
+				.try_fold(0u64, |total, (_, amount)| total.checked_add(*amount))
+				.ok_or(Error::<T>::IncorrectSharesSelection)?;
+			ensure!(
+				Self::spendable_shares(&id, &who).ge(&total_amount),
+				Error::<T>::IncorrectSharesSelection
+			);
 
-					// Calculate new shares of origin
-					// get origin shares, if it doesn't have any just set the new amount
-					let mut new_origin_metadata = MetaData { shares: 0, offers: 0, price: 0 };
+			// Each transfer follows the same rules as a single one,
+			// a failing transfer reverts the whole batch
+			for (to, amount) in transfers {
+				Self::do_transfer_shares(id, who.clone(), to, amount)?;
+			}
 
-					match ProportionalAssetToOwnerToMetadata::<T>::get(id, who.clone()) {
-						None => new_origin_metadata.shares = shares_to_buy,
-						Some(old_origin_metadata) => {
-							new_origin_metadata.shares =
-								old_origin_metadata.shares.saturating_add(shares_to_buy);
-						},
-					}
+			Ok(())
+		}
 
-					// Ensure that origin has the correct amount of Currency
-					ensure!(
-						T::Currency::free_balance(&who).ge(&amount),
-						<Error<T>>::InsufficientBalance
-					);
+		/// Set the minimum share price of the offers for an asset
+		///
+		/// The origin should be the main owner of the asset.
+		///
+		/// Owners cannot offer shares below the floor, a floor of 0 removes it.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `floor`: The minimum share price
+		#[pallet::weight(T::WeightInfo::set_price_floor())]
+		pub fn set_price_floor(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			floor: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
 
-					T::Currency::transfer(&who, &from, amount, AllowDeath)
-						.map_err(|_| DispatchError::Other("Can't transfer currency"))?;
+			ensure!(Self::is_owner_of(&who, &id), Error::<T>::NotMainOwner);
 
-					// Update storage
+			if floor.is_zero() {
+				ProportionalAssetToPriceFloor::<T>::remove(id);
+			} else {
+				ProportionalAssetToPriceFloor::<T>::insert(id, floor);
+			}
 
-					ProportionalAssetToOwnerToMetadata::<T>::set(
-						id,
-						who.clone(),
-						Some(new_origin_metadata),
-					);
+			Self::deposit_event(Event::PriceFloorSet(id, floor));
 
-					ProportionalAssetToOwnerToMetadata::<T>::set(
-						id,
-						from.clone(),
-						Some(new_from_metadata),
+			Ok(())
+		}
+
+		/// Adds shares to the existing offer of the origin
+		///
+		/// The origin should be an owner of the asset.
+		///
+		/// The existing share price of the origin cannot be zero or above `MaxSharePrice`,
+		/// and `MinReofferInterval` applies as to any offer.
+		///
+		/// The offered shares are incremented by the extra shares and clamped
+		/// to the shares held by the origin that are not in the order book or privately offered.
+		/// The price and expiry of the offer are kept.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `extra`: The amount of shares to be added to the offer
+		#[pallet::weight(T::WeightInfo::increase_offer())]
+		pub fn increase_offer(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			extra: u64,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			ensure!(extra > 0, Error::<T>::ZeroAmount);
+			ensure!(!ProportionalAssetToFrozen::<T>::get(id), Error::<T>::AssetFrozen);
+
+			match ProportionalAssetToOwnerToMetadata::<T>::get(id, who.clone()) {
+				None => Err(Error::<T>::NotAnOwner)?,
+				Some(metadata) => {
+					// The shares would be given away for free
+					ensure!(!metadata.price.is_zero(), Error::<T>::ZeroPrice);
+					ensure!(
+						metadata.price.ge(&ProportionalAssetToPriceFloor::<T>::get(id)),
+						Error::<T>::PriceBelowFloor
 					);
+					ensure!(metadata.price.le(&T::MaxSharePrice::get()), Error::<T>::PriceTooHigh);
+					let now = Self::ensure_reoffer_allowed(&id, &who)?;
+
+					// Offers can never exceed the shares held that are not committed elsewhere
+					let committed_elsewhere =
+						Self::committed_offers(&id, &who).saturating_sub(metadata.offers.into());
+					let new_metadata = MetaData {
+						shares: metadata.shares,
+						offers: metadata.offers.saturating_add(Shares(extra)).clamp_to(Shares(
+							Self::spendable_shares(&id, &who).saturating_sub(committed_elsewhere),
+						)),
+						price: metadata.price,
+					};
 
-					Self::deposit_event(Event::SharesTransferred(from, who, shares_to_buy));
+					let offered = new_metadata.offers.into();
+					ProportionalAssetToOwnerToMetadata::<T>::set(id, &who, Some(new_metadata));
+					ProportionalAssetToLastOffer::<T>::insert(id, &who, now);
+					Self::record_offer_price(&id, now, metadata.price);
+
+					Self::deposit_event(Event::SharesOffered {
+						id,
+						seller: who,
+						shares: offered,
+						price: metadata.price,
+					});
 
 					Ok(())
 				},
 			}
 		}
 
-		/// Claim main ownership of the asset
+		/// Distribute an amount to all the owners of an asset
 		///
-		/// The origin should own more than 1/2 of the asset.
+		/// The origin should have enough free balance to pay the total amount.
 		///
-		/// The main ownershipt of the asset changes
-		/// else the call fails.
+		/// Each owner receives `total_amount * shares / effective_supply` rounded down,
+		/// the rounding dust goes one unit at a time to the owners with the largest remainders.
 		///
 		/// - `id`: The identifier of the asset
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2, 1))]
-		pub fn claim_onwership(origin: OriginFor<T>, id: Identifier<T>) -> DispatchResult {
+		/// - `total_amount`: The amount to be distributed
+		#[pallet::weight(T::WeightInfo::distribute_dividend(T::MaxHolders::get()))]
+		pub fn distribute_dividend(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			total_amount: BalanceOf<T>,
+		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			let asset_owner =
-				Self::get_main_owner_by_asset(&id).ok_or(Error::<T>::AssetDoesNotExist)?;
+			Self::ensure_asset_exists(&id)?;
 
-			// Make sure that the origin is not the asset owner
-			ensure!(asset_owner != who, Error::<T>::AlreadyMainOnwer);
+			ensure!(
+				T::Currency::free_balance(&who).ge(&total_amount),
+				Error::<T>::InsufficientBalance
+			);
 
-			match ProportionalAssetToOwnerToMetadata::<T>::get(id, who.clone()) {
-				None => Err(Error::<T>::NotEnoughShares)?,
-				Some(origin_metadata) => {
-					// Make sure that origin has 50% of the shares
-					ensure!(origin_metadata.shares.gt(&50), Error::<T>::NotEnoughShares);
+			for (owner, cut) in Self::distribute_proportionally(&id, total_amount) {
+				T::Currency::transfer(&who, &owner, cut, AllowDeath)
+					.map_err(|_| Error::<T>::CurrencyTransferFailed)?;
+			}
 
-					Self::set_main_owner(who.clone(), &id);
+			Self::deposit_event(Event::DividendDistributed(id, total_amount));
 
-					Self::deposit_event(Event::MainOwnerSet(who, id));
+			Ok(())
+		}
 
-					Ok(())
-				},
+		/// Hand the main ownership of the asset over to another account
+		///
+		/// The origin should be the main owner of the asset
+		/// and the recipient should own more than 1/2 of the asset.
+		///
+		/// The creation deposit moves with the main ownership, so the recipient
+		/// should have enough free balance to reserve it.
+		///
+		/// The main ownership of the asset changes else the call fails.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `to`: The new main owner
+		#[pallet::weight(T::WeightInfo::transfer_main_ownership())]
+		pub fn transfer_main_ownership(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			to: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			ensure!(Self::is_owner_of(&who, &id), Error::<T>::NotMainOwner);
+			ensure!(who != to, Error::<T>::AlreadyMainOnwer);
+
+			// Make sure that the recipient has more than 50% of the shares
+			ensure!(
+				Self::has_majority(&id, Self::shares_of(&id, &to)),
+				Error::<T>::NotEnoughShares
+			);
+
+			Self::move_deposit(&id, &to)?;
+
+			Self::set_main_owner(to.clone(), &id);
+			T::OnMainOwnerChanged::on_changed(Some(who), to.clone(), id);
+
+			Self::deposit_event(Event::MainOwnerSet(to, id));
+
+			Ok(())
+		}
+
+		/// Halt or resume the trading of an asset
+		///
+		/// The origin should be the main owner of the asset.
+		///
+		/// While the asset is frozen, shares cannot be offered, transferred or bought.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `frozen`: Whether the trading of the asset is halted
+		#[pallet::weight(T::WeightInfo::set_frozen())]
+		pub fn set_frozen(origin: OriginFor<T>, id: Identifier<T>, frozen: bool) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			ensure!(Self::is_owner_of(&who, &id), Error::<T>::NotMainOwner);
+
+			if frozen {
+				ProportionalAssetToFrozen::<T>::insert(id, true);
+				Self::deposit_event(Event::AssetFrozen(id));
+			} else {
+				ProportionalAssetToFrozen::<T>::remove(id);
+				Self::deposit_event(Event::AssetUnfrozen(id));
 			}
+
+			Ok(())
 		}
-	}
-}
 
-impl<T: Config> Pallet<T> {
-	pub fn account_id() -> T::AccountId {
-		sp_runtime::traits::AccountIdConversion::into_account_truncating(&PALLET_ID)
-	}
+		/// Pause the trading of an asset until a block
+		///
+		/// The origin should be the main owner of the asset.
+		/// The shares cannot be bought before the block, the pause lifts by itself afterwards.
+		/// A block that has already passed lifts the pause right away.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `until`: The first block the shares can be bought again
+		#[pallet::weight(T::WeightInfo::schedule_pause())]
+		pub fn schedule_pause(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			until: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-	fn is_owner_of(who: &T::AccountId, id: &Identifier<T>) -> bool {
-		if let Some(owner) = ProportionalAssetToMainOwner::<T>::get(id) {
-			if &owner == who {
-				return true;
+			Self::ensure_asset_exists(&id)?;
+
+			ensure!(Self::is_owner_of(&who, &id), Error::<T>::NotMainOwner);
+
+			if frame_system::Pallet::<T>::block_number() < until {
+				ProportionalAssetToPauseUntil::<T>::insert(id, until);
+			} else {
+				ProportionalAssetToPauseUntil::<T>::remove(id);
 			}
+
+			Self::deposit_event(Event::TradingPaused(id, until));
+
+			Ok(())
 		}
-		false
-	}
 
-	fn get_main_owner_by_asset(id: &Identifier<T>) -> Option<T::AccountId> {
-		ProportionalAssetToMainOwner::<T>::get(id)
-	}
+		/// Set the lifecycle status of an asset
+		///
+		/// The origin should be the main owner of the asset.
+		///
+		/// Shares can only be bought while the asset is listed.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `status`: The new status of the asset
+		#[pallet::weight(T::WeightInfo::set_status())]
+		pub fn set_status(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			status: AssetStatus,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-	fn set_main_owner(who: T::AccountId, id: &Identifier<T>) {
-		ProportionalAssetToMainOwner::<T>::set(id, Some(who))
+			Self::ensure_asset_exists(&id)?;
+
+			ensure!(Self::is_owner_of(&who, &id), Error::<T>::NotMainOwner);
+
+			if status == AssetStatus::Listed {
+				ProportionalAssetToStatus::<T>::remove(id);
+			} else {
+				ProportionalAssetToStatus::<T>::insert(id, status);
+			}
+
+			Self::deposit_event(Event::StatusChanged(id, status));
+
+			Ok(())
+		}
+
+		/// Place an order in the order book of the asset
+		///
+		/// The origin should be an owner of the asset and own at least the amount to be ordered
+		/// on top of the shares already offered, privately offered or in the order book.
+		/// The share price cannot be zero or below the floor set by the main owner.
+		///
+		/// An owner can have many orders at different prices.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `shares`: The amount of shares to be sold
+		/// - `price`: The price of each share
+		#[pallet::weight(T::WeightInfo::place_order())]
+		pub fn place_order(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			shares: u64,
+			price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			ensure!(shares > 0, Error::<T>::ZeroAmount);
+			ensure!(!price.is_zero(), Error::<T>::ZeroPrice);
+			ensure!(!ProportionalAssetToFrozen::<T>::get(id), Error::<T>::AssetFrozen);
+			ensure!(
+				price.ge(&ProportionalAssetToPriceFloor::<T>::get(id)),
+				Error::<T>::PriceBelowFloor
+			);
+			ensure!(price.le(&T::MaxSharePrice::get()), Error::<T>::PriceTooHigh);
+
+			ensure!(
+				ProportionalAssetToOwnerToMetadata::<T>::contains_key(id, &who),
+				Error::<T>::NotAnOwner
+			);
+
+			// The shares cannot be committed twice
+			Self::ensure_offers_within_holdings(
+				&id,
+				&who,
+				Self::committed_offers(&id, &who).saturating_add(shares),
+			)?;
+			let ordered_shares = Self::ordered_shares(&id, &who).saturating_add(shares);
+
+			let order_id = NextOrderId::<T>::get();
+			NextOrderId::<T>::put(order_id.saturating_add(1));
+
+			Orders::<T>::insert(id, order_id, Order { seller: who.clone(), shares, price });
+			OrderedShares::<T>::insert(id, &who, ordered_shares);
+
+			Self::deposit_event(Event::OrderPlaced { id, order_id, seller: who, shares, price });
+
+			Ok(())
+		}
+
+		/// Cancel an order of the origin
+		///
+		/// The origin should be the seller of the order.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `order_id`: The identifier of the order
+		#[pallet::weight(T::WeightInfo::cancel_order())]
+		pub fn cancel_order(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			order_id: u64,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			let order = Orders::<T>::get(id, order_id).ok_or(Error::<T>::OrderNotFound)?;
+			ensure!(order.seller == who, Error::<T>::NotOrderSeller);
+
+			Orders::<T>::remove(id, order_id);
+			Self::release_ordered_shares(&id, &who, order.shares);
+
+			Self::deposit_event(Event::OrderCancelled { id, order_id });
+
+			Ok(())
+		}
+
+		/// Buy shares from an order
+		///
+		/// The origin should have balance for the price of the shares
+		/// and cannot be the seller of the order.
+		///
+		/// The price of the shares bought is transferred to the seller, less the marketplace fee,
+		/// and the order is removed once all of its shares are bought.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `order_id`: The identifier of the order
+		/// - `shares`: The amount of shares to be purchased
+		#[pallet::weight(T::WeightInfo::fill_order())]
+		pub fn fill_order(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			order_id: u64,
+			shares: u64,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			ensure!(shares > 0, Error::<T>::ZeroAmount);
+			Self::ensure_trading_allowed(&id)?;
+
+			let order = Orders::<T>::get(id, order_id).ok_or(Error::<T>::OrderNotFound)?;
+			let seller = order.seller.clone();
+
+			ensure!(who != seller, Error::<T>::IncorrectSeller);
+			ensure!(shares.le(&order.shares), Error::<T>::IncorrectSharesSelection);
+
+			let seller_metadata = ProportionalAssetToOwnerToMetadata::<T>::get(id, &seller)
+				.ok_or(Error::<T>::NotEnoughShares)?;
+			ensure!(Self::spendable_shares(&id, &seller).ge(&shares), Error::<T>::NotEnoughShares);
+
+			let price = Self::price_of(order.price, shares)?;
+			ensure!(T::Currency::free_balance(&who).ge(&price), Error::<T>::InsufficientBalance);
+
+			// Update the order book
+			let remaining_shares = order.shares.saturating_sub(shares);
+			if remaining_shares == 0 {
+				Orders::<T>::remove(id, order_id);
+			} else {
+				Orders::<T>::insert(id, order_id, Order { shares: remaining_shares, ..order });
+			}
+			Self::release_ordered_shares(&id, &seller, shares);
+
+			// Update the holdings, offers can never exceed the remaining shares
+			let new_seller_shares = seller_metadata.shares.saturating_sub(Shares(shares));
+			let new_seller_metadata = MetaData {
+				shares: new_seller_shares,
+				offers: seller_metadata.offers.clamp_to(new_seller_shares),
+				price: seller_metadata.price,
+			};
+			let new_buyer_metadata = Self::credited_metadata(&id, &who, Shares(shares))?;
+			Self::ensure_transfer_minimum(shares, new_seller_shares.into())?;
+			Self::ensure_holding_caps(
+				&id,
+				new_seller_shares.into(),
+				&who,
+				new_buyer_metadata.shares.into(),
+			)?;
+
+			Self::set_metadata(&id, &seller, new_seller_metadata);
+			Self::set_metadata(&id, &who, new_buyer_metadata);
+
+			// The currency moves once the shares have changed hands
+			Self::pay_for_shares(&id, &who, &seller, price)?;
+			Self::record_trade(&id, price);
+
+			Self::deposit_event(Event::SharesBought {
+				id,
+				buyer: who.clone(),
+				seller,
+				shares,
+				price_paid: price,
+			});
+			Self::deposit_event(Event::OrderFilled { id, order_id, buyer: who, shares });
+
+			Self::vacate_main_owner_without_majority(&id);
+
+			Ok(().into())
+		}
+
+		/// Lock shares of the origin until a block
+		///
+		/// The origin should own enough shares that are not locked already.
+		///
+		/// Locked shares cannot be offered, sold, transferred or burned until they
+		/// are unlocked. Locking again adds to the locked shares and keeps the later block.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `amount`: The amount of shares to be locked
+		/// - `until`: The block after which the shares can be unlocked
+		#[pallet::weight(T::WeightInfo::lock_shares())]
+		pub fn lock_shares(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			amount: u64,
+			until: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			ensure!(amount > 0, Error::<T>::ZeroAmount);
+			ensure!(Self::spendable_shares(&id, &who).ge(&amount), Error::<T>::NotEnoughShares);
+
+			let (locked, until) = match ProportionalAssetToLocks::<T>::get(id, &who) {
+				None => (amount, until),
+				Some((locked, locked_until)) => {
+					(locked.saturating_add(amount), locked_until.max(until))
+				},
+			};
+
+			ProportionalAssetToLocks::<T>::insert(id, &who, (locked, until));
+
+			Self::deposit_event(Event::SharesLocked(id, who, amount, until));
+
+			Ok(())
+		}
+
+		/// Unlock the locked shares of the origin
+		///
+		/// The call fails until the block of the lock has passed.
+		///
+		/// - `id`: The identifier of the asset
+		#[pallet::weight(T::WeightInfo::unlock_shares())]
+		pub fn unlock_shares(origin: OriginFor<T>, id: Identifier<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			let (locked, until) =
+				ProportionalAssetToLocks::<T>::get(id, &who).ok_or(Error::<T>::NoLockedShares)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() > until,
+				Error::<T>::SharesStillLocked
+			);
+
+			ProportionalAssetToLocks::<T>::remove(id, &who);
+
+			Self::deposit_event(Event::SharesUnlocked(id, who, locked));
+
+			Ok(())
+		}
+
+		/// Buy back offered shares of an asset as its main owner
+		///
+		/// The origin should be the main owner of the asset and have balance for the price.
+		///
+		/// The shares are bought at the price of the offer of the seller,
+		/// consolidating the ownership of the main owner.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `from`: The owner offering the shares
+		/// - `shares`: The amount of shares to be bought back
+		/// - `max_total_price`: The maximum price the origin accepts to pay for the shares
+		#[pallet::weight(T::WeightInfo::buyback_offered())]
+		pub fn buyback_offered(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			from: T::AccountId,
+			shares: u64,
+			max_total_price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			ensure!(Self::is_owner_of(&who, &id), Error::<T>::NotMainOwner);
+
+			Self::do_buy_shares(id, who, from, shares, max_total_price, max_total_price)?;
+
+			Ok(())
+		}
+
+		/// Split an asset into two new assets
+		///
+		/// The origin should be the main owner holding all the shares of the asset.
+		///
+		/// The asset is destroyed and two assets fully owned by the origin are created,
+		/// the share price is divided by `ratio` for the first asset and the rest for the second,
+		/// rounding down the price of the first asset.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `new_data_a`: The data information about the first asset
+		/// - `new_data_b`: The data information about the second asset
+		/// - `ratio`: The part of the asset represented by the first asset
+		#[pallet::weight(T::WeightInfo::split_asset()
+			.saturating_add(Pallet::<T>::proposals_cleanup_weight()))]
+		pub fn split_asset(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			new_data_a: Vec<u8>,
+			new_data_b: Vec<u8>,
+			ratio: Permill,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			ensure!(Self::is_owner_of(&who, &id), Error::<T>::NotMainOwner);
+
+			let price = ProportionalAssetToOwnerToMetadata::<T>::get(id, &who)
+				.map_or_else(Zero::zero, |metadata| metadata.price);
+			let price_a: BalanceOf<T> = T::RoundingPolicy::get()
+				.divide(
+					price.saturated_into::<u128>().saturating_mul(ratio.deconstruct() as u128),
+					Permill::one().deconstruct() as u128,
+				)
+				.saturated_into();
+			let price_b = price.saturating_sub(price_a);
+
+			Self::do_destroy_asset(id, who.clone())?;
+
+			let id_a = Self::do_create_asset(who.clone(), new_data_a, price_a, None)?;
+			let id_b = Self::do_create_asset(who, new_data_b, price_b, None)?;
+
+			Self::deposit_event(Event::AssetSplit(id, id_a, id_b));
+
+			Ok(())
+		}
+
+		/// Merge two assets into a new asset
+		///
+		/// The origin should be the main owner holding all the shares of both assets.
+		///
+		/// The assets are destroyed and an asset fully owned by the origin is created,
+		/// with the sum of the share prices of the merged assets.
+		///
+		/// - `id_a`: The identifier of the first asset
+		/// - `id_b`: The identifier of the second asset
+		/// - `merged_data`: The data information about the merged asset
+		#[pallet::weight(T::WeightInfo::merge_assets()
+			.saturating_add(Pallet::<T>::proposals_cleanup_weight().saturating_mul(2)))]
+		pub fn merge_assets(
+			origin: OriginFor<T>,
+			id_a: Identifier<T>,
+			id_b: Identifier<T>,
+			merged_data: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id_a)?;
+			Self::ensure_asset_exists(&id_b)?;
+
+			ensure!(id_a != id_b, Error::<T>::CannotMergeWithSelf);
+			ensure!(Self::is_owner_of(&who, &id_a), Error::<T>::NotMainOwner);
+			ensure!(Self::is_owner_of(&who, &id_b), Error::<T>::NotMainOwner);
+
+			let price = |id: &Identifier<T>| {
+				ProportionalAssetToOwnerToMetadata::<T>::get(id, &who)
+					.map_or_else(Zero::zero, |metadata| metadata.price)
+			};
+			let merged_price = price(&id_a).saturating_add(price(&id_b));
+
+			Self::do_destroy_asset(id_a, who.clone())?;
+			Self::do_destroy_asset(id_b, who.clone())?;
+
+			let merged_id = Self::do_create_asset(who, merged_data, merged_price, None)?;
+
+			Self::deposit_event(Event::AssetsMerged(id_a, id_b, merged_id));
+
+			Ok(())
+		}
+
+		/// Create a proposal for the owners of an asset
+		///
+		/// The origin should be an owner of the asset.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `proposal_hash`: The hash of the proposal, e.g. of a document describing a sale
+		#[pallet::weight(T::WeightInfo::create_proposal(T::MaxHolders::get()))]
+		pub fn create_proposal(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			proposal_hash: T::Hash,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			ensure!(
+				ProportionalAssetToOwnerToMetadata::<T>::contains_key(id, &who),
+				Error::<T>::NotAnOwner
+			);
+
+			OpenProposals::<T>::try_mutate(id, |open| -> DispatchResult {
+				ensure!(*open < T::MaxOpenProposals::get(), Error::<T>::TooManyProposals);
+				*open = open.saturating_add(1);
+				Ok(())
+			})?;
+
+			let proposal_id = NextProposalId::<T>::get();
+			NextProposalId::<T>::put(proposal_id.saturating_add(1));
+
+			Proposals::<T>::insert(id, proposal_id, proposal_hash);
+
+			// The votes are weighted by the shares held now, so moving shares cannot count twice
+			for (owner, metadata) in ProportionalAssetToOwnerToMetadata::<T>::iter_prefix(id) {
+				ProposalSnapshots::<T>::insert(
+					(id, proposal_id, owner),
+					u64::from(metadata.shares),
+				);
+			}
+
+			Self::deposit_event(Event::ProposalCreated { id, proposal_id, proposal_hash });
+
+			Ok(())
+		}
+
+		/// Vote on a proposal of an asset
+		///
+		/// The origin should be an owner of the asset and can vote once per proposal.
+		///
+		/// The vote is weighted by the shares held by the origin when the proposal was created.
+		/// The proposal passes and is removed once the approving shares exceed half
+		/// of the supply of the asset, and is rejected and removed once the rejecting
+		/// shares leave no majority to approve it.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `proposal_id`: The identifier of the proposal
+		/// - `approve`: Whether the origin approves the proposal
+		#[pallet::weight(T::WeightInfo::vote(T::MaxHolders::get()))]
+		pub fn vote(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			proposal_id: u64,
+			approve: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			ensure!(Proposals::<T>::contains_key(id, proposal_id), Error::<T>::ProposalNotFound);
+			ensure!(
+				!ProposalVotes::<T>::contains_key((id, proposal_id, &who)),
+				Error::<T>::AlreadyVoted
+			);
+
+			let shares = ProposalSnapshots::<T>::get((id, proposal_id, &who)).unwrap_or(0);
+			ensure!(shares > 0, Error::<T>::NotAnOwner);
+
+			ProposalVotes::<T>::insert((id, proposal_id, &who), approve);
+			let tally = ProposalTallies::<T>::mutate(id, proposal_id, |tally| {
+				if approve {
+					tally.ayes = tally.ayes.saturating_add(shares);
+				} else {
+					tally.nays = tally.nays.saturating_add(shares);
+				}
+				tally.clone()
+			});
+
+			Self::deposit_event(Event::Voted { id, proposal_id, voter: who, approve, shares });
+
+			if Self::has_majority(&id, tally.ayes) {
+				Self::close_proposal(&id, proposal_id);
+				Self::deposit_event(Event::ProposalPassed(id, proposal_id));
+			} else if !Self::has_majority(
+				&id,
+				Self::effective_supply(&id).saturating_sub(tally.nays),
+			) {
+				Self::close_proposal(&id, proposal_id);
+				Self::deposit_event(Event::ProposalRejected(id, proposal_id));
+			}
+
+			Ok(())
+		}
+
+		/// Bid for shares of a seller
+		///
+		/// The origin should be able to reserve the price of the shares
+		/// and cannot be the seller.
+		///
+		/// The price is reserved until the seller accepts or the origin cancels the bid.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `seller`: The owner the shares are bought from
+		/// - `shares`: The amount of shares to be bought
+		/// - `price`: The price of each share
+		#[pallet::weight(T::WeightInfo::make_bid())]
+		pub fn make_bid(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			seller: T::AccountId,
+			shares: u64,
+			price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			ensure!(shares > 0, Error::<T>::ZeroAmount);
+			ensure!(!price.is_zero(), Error::<T>::ZeroPrice);
+			ensure!(who != seller, Error::<T>::IncorrectSeller);
+			ensure!(!Bids::<T>::contains_key((id, &seller, &who)), Error::<T>::BidAlreadyExists);
+
+			let bid = Bid { shares, price };
+			T::Currency::reserve(&who, Self::bid_price(&bid)?)
+				.map_err(|_| Error::<T>::InsufficientBalance)?;
+
+			Bids::<T>::insert((id, &seller, &who), bid);
+
+			Self::deposit_event(Event::BidPlaced { id, seller, bidder: who, shares, price });
+
+			Ok(())
+		}
+
+		/// Accept a bid for shares of the origin
+		///
+		/// The origin should hold the shares of the bid, which are transferred to the bidder
+		/// while the reserved price, less the marketplace fee, is paid to the origin.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `bidder`: The account that made the bid
+		#[pallet::weight(T::WeightInfo::accept_bid())]
+		pub fn accept_bid(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			bidder: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			ensure!(
+				ProportionalAssetToStatus::<T>::get(id) == AssetStatus::Listed,
+				Error::<T>::AssetNotListed
+			);
+			ensure!(!Self::is_paused(&id), Error::<T>::AssetPaused);
+
+			let bid = Bids::<T>::take((id, &who, &bidder)).ok_or(Error::<T>::BidNotFound)?;
+
+			Self::do_transfer_shares(id, who.clone(), bidder.clone(), bid.shares)?;
+
+			let price = Self::bid_price(&bid)?;
+			Self::pay_reserved_for_shares(&id, &bidder, &who, price)?;
+			Self::record_trade(&id, price);
+
+			Self::deposit_event(Event::BidAccepted {
+				id,
+				seller: who,
+				bidder,
+				shares: bid.shares,
+				price: bid.price,
+			});
+
+			Ok(())
+		}
+
+		/// Cancel a bid of the origin
+		///
+		/// The reserved price is returned to the origin.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `seller`: The owner the shares were bid for
+		#[pallet::weight(T::WeightInfo::cancel_bid())]
+		pub fn cancel_bid(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			seller: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			let bid = Bids::<T>::take((id, &seller, &who)).ok_or(Error::<T>::BidNotFound)?;
+
+			T::Currency::unreserve(&who, Self::bid_price(&bid)?);
+
+			Self::deposit_event(Event::BidCancelled { id, seller, bidder: who });
+
+			Ok(())
+		}
+
+		/// Allow a spender to transfer shares of the origin
+		///
+		/// The origin should hold shares of the asset to approve a spender.
+		///
+		/// The allowance replaces any previous allowance of the spender, an amount of zero
+		/// removes it.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `spender`: The account allowed to transfer the shares
+		/// - `amount`: The amount of shares the spender is allowed to transfer
+		#[pallet::weight(T::WeightInfo::approve_shares())]
+		pub fn approve_shares(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			spender: T::AccountId,
+			amount: u64,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			if amount == 0 {
+				ProportionalAssetAllowance::<T>::remove((id, &who, &spender));
+			} else {
+				// Allowances cannot be piled up under an asset without holding any of it
+				ensure!(
+					ProportionalAssetToOwnerToMetadata::<T>::contains_key(id, &who),
+					Error::<T>::InvalidAccount
+				);
+				ProportionalAssetAllowance::<T>::insert((id, &who, &spender), amount);
+			}
+
+			Self::deposit_event(Event::Approval { id, owner: who, spender, amount });
+
+			Ok(())
+		}
+
+		/// Transfer shares of an owner on their behalf
+		///
+		/// The origin should be allowed by the owner to transfer at least the amount, which
+		/// is deducted from the allowance.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `from`: The owner of the shares
+		/// - `to`: The recipient account
+		/// - `amount`: The amount of shares to be transferred
+		#[pallet::weight(T::WeightInfo::transfer_shares_from())]
+		pub fn transfer_shares_from(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: u64,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			let allowance = ProportionalAssetAllowance::<T>::get((id, &from, &who));
+			ensure!(amount <= allowance, Error::<T>::InsufficientAllowance);
+
+			Self::do_transfer_shares(id, from.clone(), to, amount)?;
+
+			let remaining = allowance - amount;
+			if remaining == 0 {
+				ProportionalAssetAllowance::<T>::remove((id, &from, &who));
+			} else {
+				ProportionalAssetAllowance::<T>::insert((id, &from, &who), remaining);
+			}
+
+			Ok(())
+		}
+
+		/// Set the URI of the off-chain documents of an asset
+		///
+		/// The origin should be the main owner of the asset.
+		/// The URI cannot be longer than `MaxUriLength`, an empty URI removes it.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `uri`: The URI of the documents
+		#[pallet::weight(T::WeightInfo::set_metadata_uri())]
+		pub fn set_metadata_uri(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			uri: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			ensure!(Self::is_owner_of(&who, &id), Error::<T>::NotMainOwner);
+
+			Self::do_set_metadata_uri(id, uri)
+		}
+
+		/// Agree to swap shares with a counterparty
+		///
+		/// The intent replaces any previous intent of the origin with the counterparty and is
+		/// consumed once the counterparty calls `swap_shares` with the same terms.
+		///
+		/// - `counterparty`: The account the shares are swapped with
+		/// - `give_id`: The identifier of the asset the origin gives shares of
+		/// - `give_shares`: The amount of shares the origin gives
+		/// - `take_id`: The identifier of the asset the origin takes shares of
+		/// - `take_shares`: The amount of shares the origin takes
+		#[pallet::weight(T::WeightInfo::register_swap_intent())]
+		pub fn register_swap_intent(
+			origin: OriginFor<T>,
+			counterparty: T::AccountId,
+			give_id: Identifier<T>,
+			give_shares: u64,
+			take_id: Identifier<T>,
+			take_shares: u64,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&give_id)?;
+			Self::ensure_asset_exists(&take_id)?;
+
+			ensure!(give_shares > 0 && take_shares > 0, Error::<T>::ZeroAmount);
+			ensure!(who != counterparty, Error::<T>::CannotTransferToSelf);
+
+			let intent = SwapIntent { give_id, give_shares, take_id, take_shares };
+			SwapIntents::<T>::insert(&who, &counterparty, intent.clone());
+
+			Self::deposit_event(Event::SwapIntentRegistered { who, counterparty, intent });
+
+			Ok(())
+		}
+
+		/// Withdraw the swap intent of the origin with a counterparty
+		///
+		/// - `counterparty`: The account the shares would have been swapped with
+		#[pallet::weight(T::WeightInfo::cancel_swap_intent())]
+		pub fn cancel_swap_intent(
+			origin: OriginFor<T>,
+			counterparty: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			SwapIntents::<T>::take(&who, &counterparty).ok_or(Error::<T>::SwapIntentNotFound)?;
+
+			Self::deposit_event(Event::SwapIntentCancelled { who, counterparty });
+
+			Ok(())
+		}
+
+		/// Swap shares of an asset for shares of another asset with a counterparty
+		///
+		/// The counterparty should have registered a swap intent with the origin giving
+		/// `shares_b` of `id_b` for `shares_a` of `id_a`.
+		/// Both transfers happen or none of them does.
+		///
+		/// - `id_a`: The identifier of the asset the origin gives shares of
+		/// - `shares_a`: The amount of shares the origin gives
+		/// - `counterparty`: The account the shares are swapped with
+		/// - `id_b`: The identifier of the asset the counterparty gives shares of
+		/// - `shares_b`: The amount of shares the counterparty gives
+		#[pallet::weight(T::WeightInfo::swap_shares())]
+		pub fn swap_shares(
+			origin: OriginFor<T>,
+			id_a: Identifier<T>,
+			shares_a: u64,
+			counterparty: T::AccountId,
+			id_b: Identifier<T>,
+			shares_b: u64,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id_a)?;
+			Self::ensure_asset_exists(&id_b)?;
+
+			let intent = SwapIntents::<T>::take(&counterparty, &who)
+				.ok_or(Error::<T>::SwapIntentNotFound)?;
+			ensure!(
+				intent
+					== SwapIntent {
+						give_id: id_b,
+						give_shares: shares_b,
+						take_id: id_a,
+						take_shares: shares_a
+					},
+				Error::<T>::SwapIntentMismatch
+			);
+
+			Self::do_transfer_shares(id_a, who.clone(), counterparty.clone(), shares_a)?;
+			Self::do_transfer_shares(id_b, counterparty.clone(), who.clone(), shares_b)?;
+
+			Self::deposit_event(Event::SharesSwapped {
+				who,
+				counterparty,
+				id_a,
+				shares_a,
+				id_b,
+				shares_b,
+			});
+
+			Ok(())
+		}
+
+		/// Offer shares exclusively to a buyer
+		///
+		/// The origin should be an owner of the asset and own at least the amount to be offered
+		/// on top of the shares already offered, privately offered or in the order book.
+		/// The share price cannot be zero or below the floor set by the main owner.
+		///
+		/// The offer replaces any previous private offer to the buyer, offering zero shares
+		/// removes it.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `shares`: The amount of shares to be offered
+		/// - `price`: The price of each share
+		/// - `buyer`: The only account allowed to buy the shares
+		#[pallet::weight(T::WeightInfo::offer_shares_to())]
+		pub fn offer_shares_to(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			shares: u64,
+			price: BalanceOf<T>,
+			buyer: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			if shares == 0 {
+				PrivateOffers::<T>::remove((id, &who), &buyer);
+				return Ok(());
+			}
+
+			ensure!(!price.is_zero(), Error::<T>::ZeroPrice);
+			ensure!(who != buyer, Error::<T>::IncorrectSeller);
+			ensure!(!ProportionalAssetToFrozen::<T>::get(id), Error::<T>::AssetFrozen);
+			ensure!(
+				price.ge(&ProportionalAssetToPriceFloor::<T>::get(id)),
+				Error::<T>::PriceBelowFloor
+			);
+			ensure!(price.le(&T::MaxSharePrice::get()), Error::<T>::PriceTooHigh);
+
+			ensure!(
+				ProportionalAssetToOwnerToMetadata::<T>::contains_key(id, &who),
+				Error::<T>::NotAnOwner
+			);
+
+			// The shares cannot be committed twice, the replaced offer is not counted
+			let replaced =
+				PrivateOffers::<T>::get((id, &who), &buyer).map_or(0, |(shares, _)| shares);
+			Self::ensure_offers_within_holdings(
+				&id,
+				&who,
+				Self::committed_offers(&id, &who)
+					.saturating_sub(replaced)
+					.saturating_add(shares),
+			)?;
+
+			PrivateOffers::<T>::insert((id, &who), &buyer, (shares, price));
+
+			Self::deposit_event(Event::PrivateOfferMade { id, seller: who, buyer, shares, price });
+
+			Ok(())
+		}
+
+		/// Buy the shares a seller offers exclusively to the origin
+		///
+		/// The origin should be the buyer of the private offer and have balance for its price.
+		/// All the shares of the offer are bought at once.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `seller`: The owner that made the private offer
+		#[pallet::weight(T::WeightInfo::buy_private())]
+		pub fn buy_private(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			seller: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+			Self::ensure_trading_allowed(&id)?;
+
+			let (shares, share_price) =
+				PrivateOffers::<T>::take((id, &seller), &who).ok_or(Error::<T>::BuyerNotAllowed)?;
+
+			let seller_metadata = ProportionalAssetToOwnerToMetadata::<T>::get(id, &seller)
+				.ok_or(Error::<T>::NotEnoughShares)?;
+			ensure!(Self::spendable_shares(&id, &seller).ge(&shares), Error::<T>::NotEnoughShares);
+
+			let price = Self::price_of(share_price, shares)?;
+			ensure!(T::Currency::free_balance(&who).ge(&price), Error::<T>::InsufficientBalance);
+
+			// Update the holdings, offers can never exceed the remaining shares
+			let new_seller_shares = seller_metadata.shares.saturating_sub(Shares(shares));
+			let new_seller_metadata = MetaData {
+				shares: new_seller_shares,
+				offers: seller_metadata.offers.clamp_to(new_seller_shares),
+				price: seller_metadata.price,
+			};
+			let new_buyer_metadata = Self::credited_metadata(&id, &who, Shares(shares))?;
+			Self::ensure_transfer_minimum(shares, new_seller_shares.into())?;
+			Self::ensure_holding_caps(
+				&id,
+				new_seller_shares.into(),
+				&who,
+				new_buyer_metadata.shares.into(),
+			)?;
+
+			// The shares change hands before the currency moves
+			Self::set_metadata(&id, &seller, new_seller_metadata);
+			Self::set_metadata(&id, &who, new_buyer_metadata);
+
+			Self::pay_for_shares(&id, &who, &seller, price)?;
+			Self::record_trade(&id, price);
+
+			Self::deposit_event(Event::SharesBought {
+				id,
+				buyer: who,
+				seller,
+				shares,
+				price_paid: price,
+			});
+
+			Self::vacate_main_owner_without_majority(&id);
+
+			Ok(().into())
+		}
+
+		/// Reconcile the main owner of the asset with the holdings of the asset
+		///
+		/// Any origin can call it, the outcome only depends on the holdings of the asset.
+		///
+		/// The main owner slot is vacated if the main owner does not own more than 1/2
+		/// of the asset anymore. No deposit moves, a majority holder takes the role
+		/// and the creation deposit with `claim_onwership`.
+		///
+		/// - `id`: The identifier of the asset
+		#[pallet::weight(T::WeightInfo::reconcile_main_owner())]
+		pub fn reconcile_main_owner(origin: OriginFor<T>, id: Identifier<T>) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			Self::vacate_main_owner_without_majority(&id);
+
+			Ok(())
+		}
+
+		/// Auction shares of the asset to the highest bidder
+		///
+		/// The origin should hold the shares, which cannot be committed to other offers
+		/// until the auction is settled at the `ends_at` block, or at a later block when
+		/// more than `MaxAuctionSettlements` auctions end at once.
+		///
+		/// Once settled, the shares go to the highest bidder if it reached the reserve price,
+		/// the winning bid pays the seller, less the marketplace fee, and the losing bids
+		/// are unreserved. Every bid is unreserved when the asset cannot be traded anymore.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `shares`: The amount of shares to be auctioned
+		/// - `reserve_price`: The minimum price for all the shares auctioned
+		/// - `ends_at`: The block at which the auction is settled
+		#[pallet::weight(T::WeightInfo::start_auction())]
+		pub fn start_auction(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			shares: u64,
+			reserve_price: BalanceOf<T>,
+			ends_at: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			ensure!(shares > 0, Error::<T>::ZeroAmount);
+			ensure!(!reserve_price.is_zero(), Error::<T>::ZeroPrice);
+			ensure!(!ProportionalAssetToFrozen::<T>::get(id), Error::<T>::AssetFrozen);
+			ensure!(ends_at > frame_system::Pallet::<T>::block_number(), Error::<T>::AuctionEnded);
+			ensure!(!Auctions::<T>::contains_key(id), Error::<T>::AuctionExists);
+
+			ensure!(
+				ProportionalAssetToOwnerToMetadata::<T>::contains_key(id, &who),
+				Error::<T>::NotAnOwner
+			);
+
+			// The shares cannot be committed twice
+			Self::ensure_offers_within_holdings(
+				&id,
+				&who,
+				Self::committed_offers(&id, &who).saturating_add(shares),
+			)?;
+
+			Auctions::<T>::insert(
+				id,
+				Auction {
+					seller: who.clone(),
+					shares,
+					reserve_price,
+					ends_at,
+					highest_bidder: None,
+					highest_bid: Zero::zero(),
+				},
+			);
+			AuctionsEndingAt::<T>::insert(ends_at, id, ());
+
+			Self::deposit_event(Event::AuctionStarted {
+				id,
+				seller: who,
+				shares,
+				reserve_price,
+				ends_at,
+			});
+
+			Ok(())
+		}
+
+		/// Bid in the auction of the asset
+		///
+		/// The amount should reach the reserve price and outbid the highest bid.
+		/// A bidder raising their bid only reserves the difference.
+		///
+		/// - `id`: The identifier of the asset
+		/// - `amount`: The total amount bid for the shares auctioned
+		#[pallet::weight(T::WeightInfo::place_auction_bid())]
+		pub fn place_auction_bid(
+			origin: OriginFor<T>,
+			id: Identifier<T>,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_asset_exists(&id)?;
+
+			let mut auction = Auctions::<T>::get(id).ok_or(Error::<T>::AuctionNotFound)?;
+
+			ensure!(
+				frame_system::Pallet::<T>::block_number() < auction.ends_at,
+				Error::<T>::AuctionEnded
+			);
+			ensure!(who != auction.seller, Error::<T>::IncorrectSeller);
+			ensure!(
+				amount >= auction.reserve_price
+					&& (auction.highest_bidder.is_none() || amount > auction.highest_bid),
+				Error::<T>::AuctionBidTooLow
+			);
+
+			let previous_bid = match AuctionBids::<T>::get(id, &who) {
+				Some(previous_bid) => previous_bid,
+				None => {
+					ensure!(
+						(AuctionBids::<T>::iter_key_prefix(id).count() as u32)
+							< T::MaxAuctionBidders::get(),
+						Error::<T>::TooManyAuctionBidders
+					);
+					Zero::zero()
+				},
+			};
+			T::Currency::reserve(&who, amount.saturating_sub(previous_bid))
+				.map_err(|_| Error::<T>::InsufficientBalance)?;
+
+			AuctionBids::<T>::insert(id, &who, amount);
+			auction.highest_bidder = Some(who.clone());
+			auction.highest_bid = amount;
+			Auctions::<T>::insert(id, auction);
+
+			Self::deposit_event(Event::AuctionBidPlaced { id, bidder: who, amount });
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn account_id() -> T::AccountId {
+		sp_runtime::traits::AccountIdConversion::into_account_truncating(&PALLET_ID)
+	}
+
+	fn is_owner_of(who: &T::AccountId, id: &Identifier<T>) -> bool {
+		if let Some(owner) = ProportionalAssetToMainOwner::<T>::get(id) {
+			if &owner == who {
+				return true;
+			}
+		}
+		false
+	}
+
+	/// Whether a new share price is within `MaxPriceChange` of the previous price.
+	///
+	/// A zero `MaxPriceChange` or a previous price of zero allows any price.
+	fn is_price_change_allowed(previous_price: BalanceOf<T>, new_price: BalanceOf<T>) -> bool {
+		let max_change = T::MaxPriceChange::get();
+		if max_change.is_zero() || previous_price.is_zero() {
+			return true;
+		}
+
+		let change = previous_price.max(new_price) - previous_price.min(new_price);
+		change <= max_change.mul_floor(previous_price)
+	}
+
+	/// The total price reserved for a bid.
+	fn bid_price(bid: &Bid<BalanceOf<T>>) -> Result<BalanceOf<T>, Error<T>> {
+		Self::price_of(bid.price, bid.shares)
+	}
+
+	/// The price of an amount of shares, a price that overflows is rejected instead of saturated.
+	fn price_of(share_price: BalanceOf<T>, shares: u64) -> Result<BalanceOf<T>, Error<T>> {
+		let shares = BalanceOf::<T>::try_from(shares).map_err(|_| Error::<T>::PriceOverflow)?;
+		share_price.checked_mul(&shares).ok_or(Error::<T>::PriceOverflow)
+	}
+
+	/// Makes sure that the shares of an asset can be bought.
+	///
+	/// A frozen, unlisted or paused asset is rejected without charging the caller,
+	/// who could not have known of the halt when submitting the call.
+	fn ensure_trading_allowed(id: &Identifier<T>) -> Result<(), DispatchErrorWithPostInfo> {
+		Self::ensure_tradable(id).map_err(|error| DispatchErrorWithPostInfo {
+			post_info: Pays::No.into(),
+			error: error.into(),
+		})
+	}
+
+	/// Makes sure that an asset is neither frozen, unlisted nor paused.
+	fn ensure_tradable(id: &Identifier<T>) -> Result<(), Error<T>> {
+		ensure!(!ProportionalAssetToFrozen::<T>::get(id), Error::<T>::AssetFrozen);
+		ensure!(
+			ProportionalAssetToStatus::<T>::get(id) == AssetStatus::Listed,
+			Error::<T>::AssetNotListed
+		);
+		ensure!(!Self::is_paused(id), Error::<T>::AssetPaused);
+		Ok(())
+	}
+
+	/// Makes sure that the offer of a seller is open to a buyer.
+	///
+	/// The buyer should be on the allowlist of the seller, if any, and the offer not expired.
+	fn ensure_offer_open_to(
+		id: &Identifier<T>,
+		buyer: &T::AccountId,
+		from: &T::AccountId,
+	) -> Result<(), Error<T>> {
+		if let Some(allowlist) = ProportionalAssetToOfferAllowlist::<T>::get(id, from) {
+			ensure!(allowlist.contains(buyer), Error::<T>::BuyerNotAllowed);
+		}
+		ensure!(!Self::offer_expired(id, from), Error::<T>::OfferExpired);
+		Ok(())
+	}
+
+	/// Whether the offer of a seller has passed its expiry block.
+	fn offer_expired(id: &Identifier<T>, seller: &T::AccountId) -> bool {
+		ProportionalAssetToOfferExpiry::<T>::get(id, seller)
+			.map_or(false, |expires_at| frame_system::Pallet::<T>::block_number() > expires_at)
+	}
+
+	/// Makes sure that an asset exists before it is mutated.
+	fn ensure_asset_exists(id: &Identifier<T>) -> Result<(), Error<T>> {
+		ensure!(Self::asset_exists(id), Error::<T>::AssetDoesNotExist);
+		Ok(())
+	}
+
+	/// The identifier the next asset created by an account from the data will have.
+	///
+	/// It is the hash of the data, salted with the account and its nonce under `SaltedAssetIds`.
+	pub fn next_asset_id(who: &T::AccountId, data: &[u8]) -> Identifier<T> {
+		if T::SaltedAssetIds::get() {
+			T::Hashing::hash_of(&(data, who, CreatorNonce::<T>::get(who)))
+		} else {
+			T::Hashing::hash(data)
+		}
+	}
+
+	/// Whether an asset exists, even if its main owner slot has been vacated.
+	pub fn asset_exists(id: &Identifier<T>) -> bool {
+		if ProportionalAssetToMainOwner::<T>::contains_key(id) {
+			return true;
+		}
+		ProportionalAssetToOwnerToMetadata::<T>::iter_prefix_values(id).next().is_some()
+	}
+
+	fn get_main_owner_by_asset(id: &Identifier<T>) -> Option<T::AccountId> {
+		ProportionalAssetToMainOwner::<T>::get(id)
+	}
+
+	/// Stores the metadata of an owner, removing the entry once the owner holds no shares.
+	///
+	/// The owner count of the asset follows the entries added and removed,
+	/// the main owner record of the asset is only removed once the asset has no owners.
+	fn set_metadata(id: &Identifier<T>, who: &T::AccountId, metadata: MetaDataOf<T>) {
+		let is_owner = ProportionalAssetToOwnerToMetadata::<T>::contains_key(id, who);
+
+		if metadata.shares.is_zero() {
+			ProportionalAssetToOwnerToMetadata::<T>::remove(id, who);
+			ProportionalAssetToOfferExpiry::<T>::remove(id, who);
+			ProportionalAssetToLastOffer::<T>::remove(id, who);
+			ProportionalAssetToOfferAllowlist::<T>::remove(id, who);
+			ProportionalAssetToOwnerReverseIndex::<T>::remove(who, id);
+
+			if is_owner {
+				let owners = ProportionalAssetToOwnerCount::<T>::mutate(id, |count| {
+					*count = count.saturating_sub(1);
+					*count
+				});
+
+				// Nobody holds shares of the asset anymore, the caller clears the asset
+				if owners == 0 {
+					ProportionalAssetToOwnerCount::<T>::remove(id);
+					Self::remove_main_owner(id);
+				}
+			}
+		} else {
+			if !is_owner {
+				ProportionalAssetToOwnerCount::<T>::mutate(id, |count| {
+					*count = count.saturating_add(1)
+				});
+				ProportionalAssetToOwnerReverseIndex::<T>::insert(who, id, ());
+			}
+			ProportionalAssetToOwnerToMetadata::<T>::insert(id, who, metadata)
+		}
+	}
+
+	/// Vacates the main owner slot of an asset once the main owner no longer holds a majority.
+	fn vacate_main_owner_without_majority(id: &Identifier<T>) {
+		if let Some(main_owner) = Self::get_main_owner_by_asset(id) {
+			if !Self::has_majority(id, Self::shares_of(id, &main_owner)) {
+				Self::remove_main_owner(id);
+				Self::deposit_event(Event::MainOwnerVacated(*id));
+			}
+		}
+	}
+
+	/// Sets the main owner of an asset, moving the asset in `MainOwnerToAssets`.
+	fn set_main_owner(who: T::AccountId, id: &Identifier<T>) {
+		Self::remove_main_owner(id);
+		MainOwnerToAssets::<T>::insert(&who, id, ());
+		ProportionalAssetToMainOwner::<T>::insert(id, who)
+	}
+
+	/// Removes the main owner of an asset along with its entry in `MainOwnerToAssets`.
+	fn remove_main_owner(id: &Identifier<T>) {
+		if let Some(main_owner) = ProportionalAssetToMainOwner::<T>::take(id) {
+			MainOwnerToAssets::<T>::remove(main_owner, id);
+		}
+	}
+
+	/// All the owners of an asset together with their metadata, sorted by account id.
+	pub fn owners_of(id: &Identifier<T>) -> Vec<(T::AccountId, MetaDataOf<T>)> {
+		let mut owners: Vec<(T::AccountId, MetaDataOf<T>)> =
+			ProportionalAssetToOwnerToMetadata::<T>::iter_prefix(id).collect();
+		owners.sort_by(|(a, _), (b, _)| a.cmp(b));
+		owners
+	}
+
+	/// The main owner, owner count, supply and frozen state of an asset,
+	/// or `None` if the asset does not exist.
+	pub fn asset_info(id: &Identifier<T>) -> Option<AssetInfo<T::AccountId>> {
+		if !Self::asset_exists(id) {
+			return None;
+		}
+
+		Some(AssetInfo {
+			main_owner: Self::get_main_owner_by_asset(id),
+			owner_count: Self::owner_count(id),
+			effective_supply: Self::effective_supply(id),
+			frozen: ProportionalAssetToFrozen::<T>::get(id),
+		})
+	}
+
+	/// All the assets in which an account holds shares.
+	pub fn assets_of(who: &T::AccountId) -> Vec<Identifier<T>> {
+		ProportionalAssetToOwnerReverseIndex::<T>::iter_key_prefix(who).collect()
+	}
+
+	/// The latest offer prices of an asset with the block they were offered at, oldest first.
+	pub fn price_history(id: &Identifier<T>) -> Vec<(T::BlockNumber, BalanceOf<T>)> {
+		ProportionalAssetToPriceHistory::<T>::get(id).into_inner()
+	}
+
+	/// Makes sure that `MinReofferInterval` has passed since the last offer of an account,
+	/// returning the current block.
+	fn ensure_reoffer_allowed(
+		id: &Identifier<T>,
+		who: &T::AccountId,
+	) -> Result<T::BlockNumber, Error<T>> {
+		let now = frame_system::Pallet::<T>::block_number();
+		if let Some(last_offer) = ProportionalAssetToLastOffer::<T>::get(id, who) {
+			ensure!(
+				now >= last_offer.saturating_add(T::MinReofferInterval::get()),
+				Error::<T>::ReofferTooSoon
+			);
+		}
+		Ok(now)
+	}
+
+	/// Appends an offer price to the history of an asset, evicting the oldest once full.
+	fn record_offer_price(id: &Identifier<T>, now: T::BlockNumber, price: BalanceOf<T>) {
+		ProportionalAssetToPriceHistory::<T>::mutate(id, |history| {
+			if !history.is_empty() && history.len() as u32 >= T::MaxPriceHistory::get() {
+				history.remove(0);
+			}
+			// Only fails when the bound is zero, then no history is kept
+			let _ = history.try_push((now, price));
+		});
+	}
+
+	/// All the assets of which an account is the main owner.
+	pub fn main_owned_assets(who: &T::AccountId) -> Vec<Identifier<T>> {
+		MainOwnerToAssets::<T>::iter_key_prefix(who).collect()
+	}
+
+	/// A page of the owners of an asset in storage order, starting after `start_key`.
+	///
+	/// The `limit` is capped to `MAX_OWNERS_PAGE`. The returned cursor is the key
+	/// to resume from, or `None` once all the owners have been returned.
+	pub fn owners_of_paged(
+		id: &Identifier<T>,
+		start_key: Option<T::AccountId>,
+		limit: u32,
+	) -> (Vec<(T::AccountId, MetaDataOf<T>)>, Option<T::AccountId>) {
+		let mut owners_iter = match start_key {
+			None => ProportionalAssetToOwnerToMetadata::<T>::iter_prefix(id),
+			Some(key) => ProportionalAssetToOwnerToMetadata::<T>::iter_prefix_from(
+				id,
+				ProportionalAssetToOwnerToMetadata::<T>::hashed_key_for(id, key),
+			),
+		};
+
+		let owners: Vec<(T::AccountId, MetaDataOf<T>)> =
+			owners_iter.by_ref().take(limit.min(MAX_OWNERS_PAGE) as usize).collect();
+
+		let cursor = match owners_iter.next() {
+			None => None,
+			Some(_) => owners.last().map(|(owner, _)| owner.clone()),
+		};
+
+		(owners, cursor)
+	}
+
+	/// The supply of an asset after subtracting the burned shares, in units.
+	pub fn effective_supply(id: &Identifier<T>) -> u64 {
+		Self::total_units().saturating_sub(ProportionalAssetToBurnedShares::<T>::get(id))
+	}
+
+	/// The units every asset is created with, `TotalSupply` scaled by `10^ShareDecimals`.
+	pub fn total_units() -> u64 {
+		let units = u128::from(T::TotalSupply::get())
+			.saturating_mul(10u128.saturating_pow(T::ShareDecimals::get()));
+		units.min(u128::from(u64::MAX)) as u64
+	}
+
+	/// The metadata of an account for an asset, all zeros if the account is not an owner.
+	///
+	/// Nothing is written to storage. Checks on whether an account is an owner
+	/// should look the metadata up instead, a default holding is not an ownership.
+	pub fn metadata_or_default(id: &Identifier<T>, who: &T::AccountId) -> MetaDataOf<T> {
+		ProportionalAssetToOwnerToMetadata::<T>::get(id, who).unwrap_or_default()
+	}
+
+	/// The shares an account holds for an asset, 0 if the account is not an owner.
+	pub fn shares_of(id: &Identifier<T>, who: &T::AccountId) -> u64 {
+		ProportionalAssetToOwnerToMetadata::<T>::get(id, who)
+			.map_or(0, |metadata| metadata.shares.into())
+	}
+
+	/// The portion of the effective supply of an asset that an account holds.
+	pub fn ownership_percentage(id: &Identifier<T>, who: &T::AccountId) -> Permill {
+		match Self::shares_of(id, who) {
+			0 => Permill::zero(),
+			shares => Permill::from_rational(shares, Self::effective_supply(id)),
+		}
+	}
+
+	/// The portion of the effective supply of an asset that an account holds,
+	/// as a percentage scaled by `10^ShareDecimals`.
+	pub fn display_ownership(id: &Identifier<T>, who: &T::AccountId) -> u128 {
+		Self::shares_to_display(Self::shares_of(id, who), Self::effective_supply(id))
+	}
+
+	/// The percentage of `supply` that `shares` are, both in units,
+	/// scaled by `10^ShareDecimals` and rounded down.
+	pub fn shares_to_display(shares: u64, supply: u64) -> u128 {
+		if supply == 0 {
+			return 0;
+		}
+
+		(shares as u128).saturating_mul(Self::display_scale()) / supply as u128
+	}
+
+	/// The units of `supply` that a percentage scaled by `10^ShareDecimals` stands for,
+	/// rounded down.
+	pub fn display_to_shares(display: u128, supply: u64) -> u64 {
+		let shares = display.saturating_mul(supply as u128) / Self::display_scale();
+		shares.min(supply as u128) as u64
+	}
+
+	/// The displayed value of the whole supply, 100% scaled by `10^ShareDecimals`.
+	fn display_scale() -> u128 {
+		100u128.saturating_mul(10u128.saturating_pow(T::ShareDecimals::get()))
+	}
+
+	/// Whether the shares are more than half of the effective supply of the asset.
+	fn has_majority(id: &Identifier<T>, shares: u64) -> bool {
+		shares.gt(&(Self::effective_supply(id) / 2))
+	}
+
+	/// The metadata of an account after receiving shares of an asset.
+	///
+	/// The shares can never overflow within the supply, an overflow reveals a broken state.
+	fn credited_metadata(
+		id: &Identifier<T>,
+		who: &T::AccountId,
+		amount: Shares,
+	) -> Result<MetaDataOf<T>, Error<T>> {
+		let metadata = Self::metadata_or_default(id, who);
+		let new_shares = metadata.shares.checked_add(amount).ok_or(Error::<T>::ShareOverflow)?;
+
+		Ok(MetaData {
+			shares: new_shares,
+			// Offers can never exceed the shares held
+			offers: metadata.offers.clamp_to(new_shares),
+			price: metadata.price,
+		})
+	}
+
+	/// The number of accounts holding shares of an asset.
+	pub fn owner_count(id: &Identifier<T>) -> u32 {
+		ProportionalAssetToOwnerCount::<T>::get(id)
+	}
+
+	/// The URI of the off-chain documents of an asset, if it has one.
+	pub fn metadata_uri_of(id: &Identifier<T>) -> Option<Vec<u8>> {
+		ProportionalAssetToUri::<T>::get(id).map(|uri| uri.into_inner())
+	}
+
+	/// The lifecycle status of an asset.
+	pub fn status_of(id: &Identifier<T>) -> AssetStatus {
+		ProportionalAssetToStatus::<T>::get(id)
+	}
+
+	/// The total amount paid for the shares of an asset.
+	pub fn trade_volume_of(id: &Identifier<T>) -> BalanceOf<T> {
+		ProportionalAssetToTradeVolume::<T>::get(id)
+	}
+
+	/// The human-readable name of an asset, if it has one.
+	pub fn name_of(id: &Identifier<T>) -> Option<Vec<u8>> {
+		ProportionalAssetToName::<T>::get(id).map(|name| name.into_inner())
+	}
+
+	/// The sum of the shares held by all the owners of an asset.
+	///
+	/// It always equals the effective supply of an existing asset.
+	pub fn total_accounted_shares(id: &Identifier<T>) -> u64 {
+		ProportionalAssetToOwnerToMetadata::<T>::iter_prefix_values(id)
+			.fold(0u64, |total, metadata| total.saturating_add(metadata.shares.into()))
+	}
+
+	/// The shares an account has committed to sell through the offers, the order book
+	/// and the private offers of an asset.
+	pub fn committed_offers(id: &Identifier<T>, who: &T::AccountId) -> u64 {
+		ProportionalAssetToOwnerToMetadata::<T>::get(id, who)
+			.map_or(0, |metadata| metadata.offers.into())
+			.saturating_add(Self::ordered_shares(id, who))
+			.saturating_add(Self::private_offered_shares(id, who))
+			.saturating_add(Self::auctioned_shares(id, who))
+	}
+
+	/// The shares an account auctions in an asset.
+	pub fn auctioned_shares(id: &Identifier<T>, who: &T::AccountId) -> u64 {
+		Auctions::<T>::get(id)
+			.filter(|auction| auction.seller == *who)
+			.map_or(0, |auction| auction.shares)
+	}
+
+	/// Settles the auctions that have ended by `now`, returning the weight used.
+	///
+	/// At most `MaxAuctionSettlements` blocks and auctions are handled, the auctions
+	/// left over are settled in the next blocks, starting from the earliest one.
+	fn settle_ended_auctions(now: T::BlockNumber) -> Weight {
+		let mut budget = T::MaxAuctionSettlements::get();
+		let mut block = NextAuctionSettlement::<T>::get().unwrap_or(now);
+		let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+		while budget > 0 && block <= now {
+			let ids: Vec<Identifier<T>> =
+				AuctionsEndingAt::<T>::iter_key_prefix(block).take(budget as usize).collect();
+			weight = weight.saturating_add(T::DbWeight::get().reads(1));
+
+			// Every auction ending at the block is settled, move on to the next one
+			if ids.is_empty() {
+				block = block.saturating_add(One::one());
+				budget = budget.saturating_sub(1);
+				continue;
+			}
+
+			for id in ids {
+				AuctionsEndingAt::<T>::remove(block, id);
+				let bidders = Self::settle_auction(id);
+				weight = weight
+					.saturating_add(T::WeightInfo::settle_auction(bidders))
+					.saturating_add(T::DbWeight::get().writes(1));
+				budget = budget.saturating_sub(1);
+			}
+		}
+
+		NextAuctionSettlement::<T>::put(block);
+
+		weight
+	}
+
+	/// Settles the auction of an asset, returning the amount of bidders.
+	///
+	/// The shares go to the highest bidder, whose reserved bid pays the seller, less the
+	/// marketplace fee. Every other bid is unreserved, as is the winning bid when the asset
+	/// cannot be traded, the bid cannot be paid or the shares cannot be moved.
+	pub(crate) fn settle_auction(id: Identifier<T>) -> u32 {
+		let auction = match Auctions::<T>::take(id) {
+			Some(auction) => auction,
+			None => return 0,
+		};
+
+		// A settlement that fails, e.g. because the asset is frozen, leaves no trace
+		let winner = auction.highest_bidder.filter(|winner| {
+			with_transaction(|| {
+				let result = Self::ensure_tradable(&id)
+					.map_err(DispatchError::from)
+					.and_then(|_| {
+						Self::pay_reserved_for_shares(
+							&id,
+							winner,
+							&auction.seller,
+							auction.highest_bid,
+						)
+					})
+					.and_then(|_| {
+						Self::do_transfer_shares(
+							id,
+							auction.seller.clone(),
+							winner.clone(),
+							auction.shares,
+						)
+					});
+				match result {
+					Ok(()) => TransactionOutcome::Commit(result),
+					Err(_) => TransactionOutcome::Rollback(result),
+				}
+			})
+			.is_ok()
+		});
+
+		// The winning bid has been paid already
+		let mut bidders = 0u32;
+		for (bidder, amount) in AuctionBids::<T>::drain_prefix(id) {
+			bidders = bidders.saturating_add(1);
+			if winner.as_ref() != Some(&bidder) {
+				T::Currency::unreserve(&bidder, amount);
+			}
+		}
+
+		let price = if winner.is_some() { auction.highest_bid } else { Zero::zero() };
+		if winner.is_some() {
+			Self::record_trade(&id, price);
+		}
+
+		Self::deposit_event(Event::AuctionSettled {
+			id,
+			seller: auction.seller,
+			winner,
+			shares: auction.shares,
+			price,
+		});
+
+		bidders
+	}
+
+	/// The shares an account offers exclusively to buyers of an asset.
+	pub fn private_offered_shares(id: &Identifier<T>, who: &T::AccountId) -> u64 {
+		PrivateOffers::<T>::iter_prefix_values((*id, who.clone()))
+			.fold(0u64, |total, (shares, _)| total.saturating_add(shares))
+	}
+
+	/// The shares an account has in the order book of an asset.
+	pub fn ordered_shares(id: &Identifier<T>, who: &T::AccountId) -> u64 {
+		OrderedShares::<T>::get(id, who)
+	}
+
+	/// The shares of an account that are locked in an asset.
+	pub fn locked_shares(id: &Identifier<T>, who: &T::AccountId) -> u64 {
+		ProportionalAssetToLocks::<T>::get(id, who).map_or(0, |(amount, _)| amount)
+	}
+
+	/// The shares an account offers in an asset and their lowest price, if any can be bought.
+	///
+	/// Both the offer and the orders of the account count, an expired offer is not active
+	/// anymore.
+	pub fn active_offer(id: &Identifier<T>, who: &T::AccountId) -> Option<(u64, BalanceOf<T>)> {
+		let offer = ProportionalAssetToOwnerToMetadata::<T>::get(id, who)
+			.filter(|metadata| !metadata.offers.is_zero() && !Self::offer_expired(id, who))
+			.map(|metadata| (metadata.offers.into(), metadata.price));
+
+		Orders::<T>::iter_prefix_values(id)
+			.filter(|order| order.seller == *who)
+			.map(|order| (order.shares, order.price))
+			.chain(offer)
+			.reduce(|(total, best), (shares, price)| {
+				(total.saturating_add(shares), best.min(price))
+			})
+	}
+
+	/// The total shares offered for an asset and the lowest price they are offered at.
+	///
+	/// Both the offers of the owners and the order book count, expired offers do not.
+	pub fn market_depth(id: &Identifier<T>) -> (u64, Option<BalanceOf<T>>) {
+		let offers = ProportionalAssetToOwnerToMetadata::<T>::iter_prefix(id)
+			.filter(|(who, metadata)| !metadata.offers.is_zero() && !Self::offer_expired(id, who))
+			.map(|(_, metadata)| (metadata.offers.into(), metadata.price));
+		let orders = Orders::<T>::iter_prefix_values(id).map(|order| (order.shares, order.price));
+
+		offers
+			.chain(orders)
+			.fold((0u64, None), |(total_offered, best_price), (shares, price)| {
+				let best_price = match best_price {
+					_ if price.is_zero() => best_price,
+					Some(best) if best <= price => Some(best),
+					_ => Some(price),
+				};
+				(total_offered.saturating_add(shares), best_price)
+			})
+	}
+
+	/// Splits a total between the owners of an asset in proportion to their shares.
+	///
+	/// Each cut is rounded with the `RoundingPolicy`. Under `LargestRemainder` the remaining
+	/// units go one at a time to the owners with the largest remainders, so the cuts always
+	/// sum up to the total.
+	pub fn distribute_proportionally(
+		id: &Identifier<T>,
+		total: BalanceOf<T>,
+	) -> Vec<(T::AccountId, BalanceOf<T>)> {
+		let rounding = T::RoundingPolicy::get();
+		let owners = Self::owners_of(id);
+		let supply: u64 = owners
+			.iter()
+			.fold(0, |supply, (_, metadata)| supply.saturating_add(metadata.shares.0));
+		if supply == 0 {
+			return Vec::new();
+		}
+
+		// With `total = whole * supply + rest`, the cut of an owner is `whole * shares` plus
+		// `rest * shares / supply`, which cannot overflow since `rest` is below the supply
+		let supply_balance: BalanceOf<T> = supply.saturated_into();
+		let whole = total / supply_balance;
+		let rest: u128 = (total % supply_balance).saturated_into();
+		let two: BalanceOf<T> = 2u32.into();
+
+		let mut cuts = Vec::with_capacity(owners.len());
+		let mut remainders = Vec::with_capacity(owners.len());
+		let mut distributed: BalanceOf<T> = Zero::zero();
+
+		for (index, (owner, metadata)) in owners.into_iter().enumerate() {
+			let base = whole.saturating_mul(metadata.shares.0.saturated_into());
+			let scaled = rest.saturating_mul(metadata.shares.0 as u128);
+			let quotient = scaled / supply as u128;
+			let remainder = scaled % supply as u128;
+			let odd_quotient = (base % two == One::one()) != (quotient % 2 == 1);
+
+			// The cut of an owner is never above the total
+			let mut cut = base.saturating_add(quotient.saturated_into());
+			if rounding.rounds_up(remainder, supply as u128, odd_quotient) {
+				cut = cut.saturating_add(One::one());
+			}
+			distributed = distributed.saturating_add(cut);
+			remainders.push((remainder, index));
+			cuts.push((owner, cut));
+		}
+
+		if rounding != Rounding::LargestRemainder {
+			return cuts;
+		}
+
+		// Ties go to the owner that comes first
+		let leftover: usize = total.saturating_sub(distributed).saturated_into();
+		remainders.sort_unstable_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+		for (_, index) in remainders.into_iter().take(leftover) {
+			cuts[index].1 = cuts[index].1.saturating_add(One::one());
+		}
+
+		cuts
+	}
+
+	/// Whether an account can claim the main ownership of an asset.
+	///
+	/// The account should hold a majority of the asset and not be its main owner already,
+	/// an asset that does not exist cannot be claimed.
+	pub fn can_claim_ownership(id: &Identifier<T>, who: &T::AccountId) -> bool {
+		Self::asset_exists(id)
+			&& !Self::is_owner_of(who, id)
+			&& Self::has_majority(id, Self::shares_of(id, who))
+	}
+
+	/// Whether the trading of an asset is paused at the current block.
+	pub fn is_paused(id: &Identifier<T>) -> bool {
+		ProportionalAssetToPauseUntil::<T>::get(id)
+			.map_or(false, |until| frame_system::Pallet::<T>::block_number() < until)
+	}
+
+	/// The shares of an account that are not locked and can be moved.
+	pub fn spendable_shares(id: &Identifier<T>, who: &T::AccountId) -> u64 {
+		Self::shares_of(id, who).saturating_sub(Self::locked_shares(id, who))
+	}
+
+	/// The amount a buyer pays for shares offered by `from`, without buying them.
+	///
+	/// The same checks as buying the shares apply, including the holding caps,
+	/// the minimum transfer and the free balance of the buyer. The marketplace fee
+	/// is taken out of this amount, the seller receives the remainder.
+	pub fn quote_buy(
+		id: &Identifier<T>,
+		buyer: &T::AccountId,
+		from: &T::AccountId,
+		shares: u64,
+	) -> Result<BalanceOf<T>, Error<T>> {
+		ensure!(shares > 0, Error::<T>::ZeroAmount);
+		Self::ensure_asset_exists(id)?;
+		Self::ensure_tradable(id)?;
+		ensure!(buyer != from, Error::<T>::IncorrectSeller);
+
+		let metadata = ProportionalAssetToOwnerToMetadata::<T>::get(id, from)
+			.ok_or(Error::<T>::IncorrectSeller)?;
+		ensure!(!metadata.offers.is_zero(), Error::<T>::IncorrectSeller);
+		ensure!(
+			Shares(shares) <= metadata.offers && shares <= Self::spendable_shares(id, from),
+			Error::<T>::IncorrectSharesSelection
+		);
+		Self::ensure_offer_open_to(id, buyer, from)?;
+
+		let remaining: u64 = metadata.shares.saturating_sub(Shares(shares)).into();
+		let received: u64 = Self::credited_metadata(id, buyer, Shares(shares))?.shares.into();
+		Self::ensure_transfer_minimum(shares, remaining)?;
+		Self::ensure_holding_caps(id, remaining, buyer, received)?;
+
+		let price = Self::price_of(metadata.price, shares)?;
+		ensure!(T::Currency::free_balance(buyer).ge(&price), Error::<T>::InsufficientBalance);
+
+		Ok(price)
+	}
+
+	fn release_ordered_shares(id: &Identifier<T>, who: &T::AccountId, shares: u64) {
+		OrderedShares::<T>::mutate_exists(id, who, |ordered| {
+			*ordered = ordered.map(|ordered| ordered.saturating_sub(shares)).filter(|o| *o > 0)
+		});
+	}
+
+	/// Transfers shares of an asset from an owner to another account.
+	pub(crate) fn do_transfer_shares(
+		id: Identifier<T>,
+		who: T::AccountId,
+		to: T::AccountId,
+		amount: u64,
+	) -> DispatchResult {
+		ensure!(amount > 0, Error::<T>::ZeroAmount);
+		ensure!(!ProportionalAssetToFrozen::<T>::get(id), Error::<T>::AssetFrozen);
+
+		// Reading and writing the same metadata twice would mint shares
+		ensure!(who != to, Error::<T>::CannotTransferToSelf);
+
+		match ProportionalAssetToOwnerToMetadata::<T>::get(id, who.clone()) {
+			None => Err(Error::<T>::InvalidAccount)?,
+			Some(origin_metadata) => {
+				ensure!(
+					Self::spendable_shares(&id, &who).ge(&amount),
+					Error::<T>::IncorrectSharesSelection
+				);
+
+				// Decrease origin shares
+				let new_origin_shares = origin_metadata.shares.saturating_sub(Shares(amount));
+
+				let new_to_metadata = Self::credited_metadata(&id, &to, Shares(amount))?;
+
+				Self::ensure_transfer_minimum(amount, new_origin_shares.into())?;
+				Self::ensure_holding_caps(
+					&id,
+					new_origin_shares.into(),
+					&to,
+					new_to_metadata.shares.into(),
+				)?;
+
+				// The shares of both accounts can never exceed the total supply
+				ensure!(
+					new_origin_shares
+						.saturating_add(new_to_metadata.shares)
+						.le(&Shares(Self::effective_supply(&id))),
+					Error::<T>::TotalSupplyExceeded
+				);
+
+				Self::set_metadata(&id, &to, new_to_metadata);
+
+				// Update the origin metadata, offers can never exceed the remaining shares
+				let new_origin_offers = if T::ClearOffersOnTransfer::get() {
+					Shares(0)
+				} else {
+					origin_metadata.offers.clamp_to(new_origin_shares)
+				};
+				let new_origin_metadata = MetaData {
+					shares: new_origin_shares,
+					offers: new_origin_offers,
+					price: origin_metadata.price,
+				};
+
+				Self::set_metadata(&id, &who, new_origin_metadata);
+
+				Self::deposit_event(Event::SharesTransferred(id, who, to, amount));
+
+				Self::vacate_main_owner_without_majority(&id);
+
+				Ok(())
+			},
+		}
+	}
+
+	/// Moves shares of an asset by force, regardless of freezes, locks and the minimum transfer.
+	///
+	/// The holding caps still apply. The offers and the locks of the sender can never exceed
+	/// the shares left.
+	pub(crate) fn do_force_transfer_shares(
+		id: Identifier<T>,
+		who: T::AccountId,
+		to: T::AccountId,
+		amount: u64,
+	) -> DispatchResult {
+		ensure!(amount > 0, Error::<T>::ZeroAmount);
+
+		// Reading and writing the same metadata twice would mint shares
+		ensure!(who != to, Error::<T>::CannotTransferToSelf);
+
+		let origin_metadata = ProportionalAssetToOwnerToMetadata::<T>::get(id, &who)
+			.ok_or(Error::<T>::InvalidAccount)?;
+		ensure!(origin_metadata.shares.ge(&Shares(amount)), Error::<T>::IncorrectSharesSelection);
+
+		let new_origin_shares = origin_metadata.shares.saturating_sub(Shares(amount));
+		let new_to_metadata = Self::credited_metadata(&id, &to, Shares(amount))?;
+		Self::ensure_holding_caps(
+			&id,
+			new_origin_shares.into(),
+			&to,
+			new_to_metadata.shares.into(),
+		)?;
+
+		Self::set_metadata(&id, &to, new_to_metadata);
+		Self::set_metadata(
+			&id,
+			&who,
+			MetaData {
+				shares: new_origin_shares,
+				offers: origin_metadata.offers.clamp_to(new_origin_shares),
+				price: origin_metadata.price,
+			},
+		);
+
+		ProportionalAssetToLocks::<T>::mutate_exists(id, &who, |lock| {
+			if let Some((locked, _)) = lock {
+				*locked = (*locked).min(new_origin_shares.into());
+			}
+			if matches!(lock, Some((0, _))) {
+				*lock = None;
+			}
+		});
+
+		Self::deposit_event(Event::SharesTransferred(id, who, to, amount));
+
+		Self::vacate_main_owner_without_majority(&id);
+
+		Ok(())
+	}
+
+	/// Burns shares of an asset held by an owner.
+	pub(crate) fn do_burn_shares(
+		id: Identifier<T>,
+		who: T::AccountId,
+		amount: u64,
+	) -> DispatchResult {
+		match ProportionalAssetToOwnerToMetadata::<T>::get(id, who.clone()) {
+			None => Err(Error::<T>::NotAnOwner)?,
+			Some(metadata) => {
+				ensure!(Self::spendable_shares(&id, &who).ge(&amount), Error::<T>::NotEnoughShares);
+
+				let new_shares = metadata.shares.saturating_sub(Shares(amount));
+
+				let new_metadata = MetaData {
+					shares: new_shares,
+					offers: metadata.offers.clamp_to(new_shares),
+					price: metadata.price,
+				};
+
+				Self::set_metadata(&id, &who, new_metadata);
+
+				ProportionalAssetToBurnedShares::<T>::mutate(id, |burned| {
+					*burned = burned.saturating_add(amount)
+				});
+
+				Self::deposit_event(Event::SharesBurned(id, who, amount));
+
+				Self::vacate_main_owner_without_majority(&id);
+
+				// Once the whole supply is burned nothing of the asset is kept,
+				// so the same data can create a fresh asset
+				if Self::owner_count(&id) == 0 {
+					Self::clear_asset(id);
+					Self::deposit_event(Event::AssetEmptied(id));
+				}
+
+				Ok(())
+			},
+		}
+	}
+
+	/// Makes an account holding a majority the main owner of an asset.
+	fn do_take_main_ownership(id: Identifier<T>, who: T::AccountId) -> DispatchResult {
+		// Make sure that origin has more than 50% of the shares
+		ensure!(
+			ProportionalAssetToOwnerToMetadata::<T>::contains_key(id, &who)
+				&& Self::has_majority(&id, Self::shares_of(&id, &who)),
+			Error::<T>::NotEnoughShares
+		);
+
+		Self::move_deposit(&id, &who)?;
+
+		let old_main_owner = Self::get_main_owner_by_asset(&id);
+		Self::set_main_owner(who.clone(), &id);
+		T::OnMainOwnerChanged::on_changed(old_main_owner, who.clone(), id);
+
+		Self::deposit_event(Event::MainOwnerSet(who, id));
+
+		Ok(())
+	}
+
+	/// Hands the creation deposit of an asset over to another account.
+	///
+	/// The account reserves the deposit before the previous depositor gets it back.
+	fn move_deposit(id: &Identifier<T>, to: &T::AccountId) -> DispatchResult {
+		if let Some((depositor, deposit)) = ProportionalAssetToDeposit::<T>::get(id) {
+			if &depositor != to {
+				T::Currency::reserve(to, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+				T::Currency::unreserve(&depositor, deposit);
+				ProportionalAssetToDeposit::<T>::insert(id, (to.clone(), deposit));
+				Self::deposit_refunded_event(*id, depositor, deposit);
+				Self::deposit_reserved_event(*id, to.clone(), deposit);
+			}
+		}
+		Ok(())
+	}
+
+	/// Reports a reserved creation deposit, a deposit of zero moves no funds.
+	fn deposit_reserved_event(id: Identifier<T>, who: T::AccountId, deposit: BalanceOf<T>) {
+		if !deposit.is_zero() {
+			Self::deposit_event(Event::DepositReserved(id, who, deposit));
+		}
+	}
+
+	/// Reports a returned creation deposit, a deposit of zero moves no funds.
+	fn deposit_refunded_event(id: Identifier<T>, who: T::AccountId, deposit: BalanceOf<T>) {
+		if !deposit.is_zero() {
+			Self::deposit_event(Event::DepositRefunded(id, who, deposit));
+		}
+	}
+
+	/// Pays the seller for shares from the free balance of the buyer.
+	///
+	/// The marketplace fee is rounded down and goes to the fee collector,
+	/// the seller receives the remainder.
+	fn pay_for_shares(
+		id: &Identifier<T>,
+		buyer: &T::AccountId,
+		seller: &T::AccountId,
+		price: BalanceOf<T>,
+	) -> DispatchResult {
+		let fee = T::MarketplaceFee::get().mul_floor(price);
+
+		T::Currency::transfer(buyer, seller, price.saturating_sub(fee), AllowDeath)
+			.map_err(|_| Error::<T>::CurrencyTransferFailed)?;
+
+		if !fee.is_zero() {
+			T::Currency::transfer(buyer, &T::FeeCollector::get(), fee, AllowDeath)
+				.map_err(|_| Error::<T>::CurrencyTransferFailed)?;
+
+			Self::deposit_event(Event::FeeCharged(*id, fee));
+		}
+
+		Ok(())
+	}
+
+	/// Pays the seller for shares from the balance the buyer has reserved for them.
+	///
+	/// The same marketplace fee as `pay_for_shares` applies. The whole price has to move,
+	/// a partially unreserved amount is rejected.
+	fn pay_reserved_for_shares(
+		id: &Identifier<T>,
+		buyer: &T::AccountId,
+		seller: &T::AccountId,
+		price: BalanceOf<T>,
+	) -> DispatchResult {
+		let fee = T::MarketplaceFee::get().mul_floor(price);
+
+		let repatriate = |to: &T::AccountId, amount: BalanceOf<T>| -> DispatchResult {
+			let missing = T::Currency::repatriate_reserved(
+				buyer,
+				to,
+				amount,
+				frame_support::traits::BalanceStatus::Free,
+			)?;
+			ensure!(missing.is_zero(), Error::<T>::CurrencyTransferFailed);
+			Ok(())
+		};
+
+		repatriate(seller, price.saturating_sub(fee))?;
+
+		if !fee.is_zero() {
+			repatriate(&T::FeeCollector::get(), fee)?;
+
+			Self::deposit_event(Event::FeeCharged(*id, fee));
+		}
+
+		Ok(())
+	}
+
+	/// Adds the amount paid in a trade to the volume of an asset.
+	fn record_trade(id: &Identifier<T>, paid: BalanceOf<T>) {
+		ProportionalAssetToTradeVolume::<T>::mutate(id, |volume| {
+			*volume = volume.saturating_add(paid)
+		});
+	}
+
+	/// Makes sure that the shares committed to all the offer channels can be sold.
+	fn ensure_offers_within_holdings(
+		id: &Identifier<T>,
+		who: &T::AccountId,
+		committed: u64,
+	) -> DispatchResult {
+		ensure!(committed <= Self::spendable_shares(id, who), Error::<T>::OverCommittedOffers);
+		Ok(())
+	}
+
+	/// Makes sure that at least the minimum amount of shares is moved.
+	///
+	/// A smaller amount is only moved when it empties the holding of the sender.
+	fn ensure_transfer_minimum(amount: u64, remaining: u64) -> Result<(), Error<T>> {
+		ensure!(
+			remaining == 0 || amount >= T::MinShareTransfer::get(),
+			Error::<T>::TransferBelowMinimum
+		);
+		Ok(())
+	}
+
+	/// Makes sure that moving shares keeps the holdings of an asset within the caps.
+	///
+	/// The sender either keeps at least the minimum holding or nothing, the recipient
+	/// ends up with at least the minimum holding and at most `MaxHoldingPercent` of the supply,
+	/// and a new recipient fits in the holders cap.
+	fn ensure_holding_caps(
+		id: &Identifier<T>,
+		remaining: u64,
+		to: &T::AccountId,
+		received: u64,
+	) -> Result<(), Error<T>> {
+		let min_holding = T::MinHolding::get();
+		ensure!(remaining == 0 || remaining >= min_holding, Error::<T>::HoldingBelowMinimum);
+		ensure!(received >= min_holding, Error::<T>::HoldingBelowMinimum);
+
+		let exempt = T::MainOwnerExemptFromCap::get() && Self::is_owner_of(to, id);
+		ensure!(
+			exempt || received <= T::MaxHoldingPercent::get().mul_floor(Self::effective_supply(id)),
+			Error::<T>::HoldingCapExceeded
+		);
+
+		if !ProportionalAssetToOwnerToMetadata::<T>::contains_key(id, to) {
+			// The sender leaves the holders once all of the shares are moved
+			let holders =
+				Self::owner_count(id).saturating_add(1).saturating_sub((remaining == 0) as u32);
+			ensure!(holders <= T::MaxHolders::get(), Error::<T>::TooManyHolders);
+		}
+
+		Ok(())
+	}
+
+	/// Buys offered shares of an asset from a seller at the price of the offer.
+	pub(crate) fn do_buy_shares(
+		id: Identifier<T>,
+		who: T::AccountId,
+		from: T::AccountId,
+		shares_to_buy: u64,
+		amount_sent: BalanceOf<T>,
+		max_total_price: BalanceOf<T>,
+	) -> DispatchResult {
+		ensure!(shares_to_buy > 0, Error::<T>::ZeroAmount);
+		Self::ensure_tradable(&id)?;
+
+		// Ensure that the sender is not the seller
+		ensure!(who != from, Error::<T>::IncorrectSeller);
+
+		// Any owner of a portion of the asset can be a seller
+		match ProportionalAssetToOwnerToMetadata::<T>::get(id, from.clone()) {
+			None => Err(Error::<T>::IncorrectSeller)?,
+			Some(from_metadata) => {
+				// make sure that "from" offers shares for sale
+				ensure!(!from_metadata.offers.is_zero(), Error::<T>::IncorrectSeller);
+
+				// make sure that "from" owns more than the specified shares_to_buy
+				ensure!(
+					from_metadata.shares.ge(&Shares(shares_to_buy)),
+					Error::<T>::IncorrectAmount
+				);
+
+				// Only the price owed is paid, any surplus stays with the origin
+				let price_to_pay = Self::price_of(from_metadata.price, shares_to_buy)?;
+
+				// The seller may have raised the price after the origin submitted the call
+				ensure!(price_to_pay.le(&max_total_price), Error::<T>::PriceSlippageExceeded);
+
+				// Ensure that the amount sent is correnct
+				ensure!(amount_sent.ge(&price_to_pay), Error::<T>::IncorrectAmount);
+
+				// Make sure that shares_to_buy <= from's shares that are not locked
+				ensure!(
+					shares_to_buy.le(&Self::spendable_shares(&id, &from)),
+					Error::<T>::IncorrectSharesSelection
+				);
+
+				// Make sure that shares_to_buy <= offered
+				ensure!(
+					Shares(shares_to_buy).le(&from_metadata.offers),
+					Error::<T>::IncorrectSharesSelection
+				);
+
+				// Make sure that the buyer is allowed to buy the offer and it has not expired
+				Self::ensure_offer_open_to(&id, &who, &from)?;
+
+				// Decrease for owner of the share
+				let new_from_shares = from_metadata.shares.saturating_sub(Shares(shares_to_buy));
+
+				// Update from offers
+				let new_from_offers = from_metadata.offers.saturating_sub(Shares(shares_to_buy));
+
+				let new_from_metadata = MetaData {
+					shares: new_from_shares,
+					offers: new_from_offers,
+					price: from_metadata.price,
+				};
+
+				// Calculate new shares of origin, an owner keeps the offer and price it already has
+				let new_origin_metadata =
+					Self::credited_metadata(&id, &who, Shares(shares_to_buy))?;
+
+				Self::ensure_transfer_minimum(shares_to_buy, new_from_shares.into())?;
+				Self::ensure_holding_caps(
+					&id,
+					new_from_shares.into(),
+					&who,
+					new_origin_metadata.shares.into(),
+				)?;
+
+				// Ensure that origin has the correct amount of Currency
+				ensure!(
+					T::Currency::free_balance(&who).ge(&price_to_pay),
+					<Error<T>>::InsufficientBalance
+				);
+
+				let buyer_shares_after: u64 = new_origin_metadata.shares.into();
+				let seller_shares_after: u64 = new_from_metadata.shares.into();
+
+				// Update storage before any currency moves, so that a currency hook calling
+				// back into the pallet observes the holdings after the trade.
+				// A failed transfer reverts these writes together with the call.
+
+				Self::set_metadata(&id, &who, new_origin_metadata);
+
+				Self::set_metadata(&id, &from, new_from_metadata);
+
+				Self::record_trade(&id, price_to_pay);
+
+				Self::pay_for_shares(&id, &who, &from, price_to_pay)?;
+
+				Self::deposit_event(Event::SharesBought {
+					id,
+					buyer: who.clone(),
+					seller: from.clone(),
+					shares: shares_to_buy,
+					price_paid: price_to_pay,
+				});
+
+				Self::deposit_event(Event::TradeSettled {
+					id,
+					buyer: who,
+					seller: from,
+					shares: shares_to_buy,
+					price: from_metadata.price,
+					buyer_shares_after,
+					seller_shares_after,
+				});
+
+				Self::vacate_main_owner_without_majority(&id);
+
+				Ok(())
+			},
+		}
+	}
+
+	/// Creates an asset fully owned by an account, reserving the creation deposit.
+	pub(crate) fn do_create_asset(
+		who: T::AccountId,
+		data: Vec<u8>,
+		share_price: BalanceOf<T>,
+		name: Option<Vec<u8>>,
+	) -> Result<Identifier<T>, DispatchError> {
+		ensure!(!data.is_empty(), Error::<T>::EmptyAssetData);
+		ensure!(data.len() <= T::MaxDataLength::get() as usize, Error::<T>::AssetDataTooLong);
+		ensure!(share_price.le(&T::MaxSharePrice::get()), Error::<T>::PriceTooHigh);
+
+		let name = name
+			.map(BoundedVec::<u8, T::MaxNameLength>::try_from)
+			.transpose()
+			.map_err(|_| Error::<T>::NameTooLong)?;
+
+		let id = Self::next_asset_id(&who, &data);
+
+		// Unless salted, any account may compute the same id, so the asset is looked up
+		// regardless of the caller
+		ensure!(!Self::asset_exists(&id), Error::<T>::AssetAlreadyExists);
+
+		let deposit = T::AssetDeposit::get();
+
+		// Reserve the creation deposit, returned when the asset is destroyed
+		T::Currency::reserve(&who, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+		ProportionalAssetToDeposit::<T>::insert(id, (who.clone(), deposit));
+		Self::deposit_reserved_event(id, who.clone(), deposit);
+
+		let metadata =
+			MetaData { shares: Shares(Self::total_units()), offers: Shares(0), price: share_price };
+
+		// Create the asset & set the owner
+		// Initialize owner with all the supply
+		// Initialize assets offers to 0
+		Self::set_metadata(&id, &who, metadata);
+
+		// Set the main owner of the asset
+		Self::set_main_owner(who.clone(), &id);
+		ProportionalAssetToName::<T>::set(id, name);
+
+		if T::SaltedAssetIds::get() {
+			CreatorNonce::<T>::mutate(&who, |nonce| *nonce = nonce.saturating_add(1));
+		}
+
+		Self::deposit_event(Event::ProportionalAssetInitialized(
+			id,
+			who,
+			Self::total_units(),
+			share_price,
+		));
+
+		Ok(id)
+	}
+
+	/// Sets the metadata URI of an asset, an empty URI removes it.
+	fn do_set_metadata_uri(id: Identifier<T>, uri: Vec<u8>) -> DispatchResult {
+		if uri.is_empty() {
+			ProportionalAssetToUri::<T>::remove(id);
+		} else {
+			let uri = BoundedVec::<u8, T::MaxUriLength>::try_from(uri)
+				.map_err(|_| Error::<T>::UriTooLong)?;
+			ProportionalAssetToUri::<T>::insert(id, uri);
+		}
+
+		Self::deposit_event(Event::MetadataUriSet(id));
+
+		Ok(())
+	}
+
+	/// Removes an asset held only by an account, returning the creation deposit.
+	pub(crate) fn do_destroy_asset(id: Identifier<T>, who: T::AccountId) -> DispatchResult {
+		// Make sure that nobody else holds shares of the asset
+		ensure!(
+			ProportionalAssetToOwnerToMetadata::<T>::iter_key_prefix(id).all(|owner| owner == who),
+			Error::<T>::AssetHasMultipleOwners
+		);
+
+		Self::purge_asset(id);
+
+		Ok(())
+	}
+
+	/// Removes an asset from storage whoever holds its shares.
+	///
+	/// The bids and the creation deposit are unreserved.
+	fn purge_asset(id: Identifier<T>) {
+		Self::clear_asset(id);
+
+		Self::deposit_event(Event::AssetEmptied(id));
+		Self::deposit_event(Event::AssetDestroyed(id));
+	}
+
+	/// Removes every entry of an asset from storage, unreserving the bids and the deposit.
+	fn clear_asset(id: Identifier<T>) {
+		for (owner, _) in ProportionalAssetToOwnerToMetadata::<T>::drain_prefix(id) {
+			ProportionalAssetToOwnerReverseIndex::<T>::remove(&owner, id);
+			let _ = PrivateOffers::<T>::clear_prefix((id, owner), u32::MAX, None);
+		}
+		let _ = ProportionalAssetToOfferExpiry::<T>::clear_prefix(id, u32::MAX, None);
+		let _ = ProportionalAssetToLastOffer::<T>::clear_prefix(id, u32::MAX, None);
+		let _ = ProportionalAssetToOfferAllowlist::<T>::clear_prefix(id, u32::MAX, None);
+		Self::remove_main_owner(&id);
+		ProportionalAssetToBurnedShares::<T>::remove(id);
+		ProportionalAssetToOwnerCount::<T>::remove(id);
+		ProportionalAssetToFrozen::<T>::remove(id);
+		ProportionalAssetToPauseUntil::<T>::remove(id);
+		ProportionalAssetToStatus::<T>::remove(id);
+		ProportionalAssetToTradeVolume::<T>::remove(id);
+		ProportionalAssetToName::<T>::remove(id);
+		ProportionalAssetToUri::<T>::remove(id);
+		let _ = Orders::<T>::clear_prefix(id, u32::MAX, None);
+		let _ = OrderedShares::<T>::clear_prefix(id, u32::MAX, None);
+		let _ = Proposals::<T>::clear_prefix(id, u32::MAX, None);
+		let _ = ProposalTallies::<T>::clear_prefix(id, u32::MAX, None);
+		let _ = ProposalVotes::<T>::clear_prefix((id,), u32::MAX, None);
+		let _ = ProposalSnapshots::<T>::clear_prefix((id,), u32::MAX, None);
+		OpenProposals::<T>::remove(id);
+		let _ = ProportionalAssetAllowance::<T>::clear_prefix((id,), u32::MAX, None);
+		let _ = ProportionalAssetToLocks::<T>::clear_prefix(id, u32::MAX, None);
+		ProportionalAssetToPriceHistory::<T>::remove(id);
+		ProportionalAssetToPriceFloor::<T>::remove(id);
+
+		// The bids can never be accepted anymore, the bidders get back the reserved price
+		if let Some(auction) = Auctions::<T>::take(id) {
+			AuctionsEndingAt::<T>::remove(auction.ends_at, id);
+		}
+		for (bidder, amount) in AuctionBids::<T>::drain_prefix(id) {
+			T::Currency::unreserve(&bidder, amount);
+		}
+		for ((_, bidder), bid) in Bids::<T>::drain_prefix((id,)) {
+			if let Ok(price) = Self::bid_price(&bid) {
+				T::Currency::unreserve(&bidder, price);
+			}
+		}
+
+		if let Some((depositor, deposit)) = ProportionalAssetToDeposit::<T>::take(id) {
+			T::Currency::unreserve(&depositor, deposit);
+			Self::deposit_refunded_event(id, depositor, deposit);
+		}
+	}
+
+	/// Checks the invariants of every asset.
+	///
+	/// The shares of the owners together with the burned shares add up to the total supply,
+	/// and a main owner always holds a majority of the effective supply.
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn do_try_state() -> Result<(), &'static str> {
+		let mut ids: Vec<Identifier<T>> = ProportionalAssetToOwnerToMetadata::<T>::iter_keys()
+			.map(|(id, _)| id)
+			.chain(ProportionalAssetToMainOwner::<T>::iter_keys())
+			.collect();
+		ids.sort();
+		ids.dedup();
+
+		for id in ids {
+			let burned = ProportionalAssetToBurnedShares::<T>::get(id);
+			ensure!(
+				Self::total_accounted_shares(&id).saturating_add(burned) == Self::total_units(),
+				"The shares of an asset do not add up to the total supply"
+			);
+
+			if let Some(main_owner) = Self::get_main_owner_by_asset(&id) {
+				ensure!(
+					Self::has_majority(&id, Self::shares_of(&id, &main_owner)),
+					"The main owner of an asset does not hold a majority"
+				);
+			}
+		}
+
+		for (main_owner, id) in MainOwnerToAssets::<T>::iter_keys() {
+			ensure!(
+				Self::get_main_owner_by_asset(&id) == Some(main_owner),
+				"An asset is indexed under an account that is not its main owner"
+			);
+		}
+		ensure!(
+			ProportionalAssetToMainOwner::<T>::iter()
+				.all(|(id, main_owner)| MainOwnerToAssets::<T>::contains_key(main_owner, id)),
+			"The main owner of an asset is missing from the index"
+		);
+
+		Ok(())
+	}
+
+	/// Remove an open proposal of an asset with its tally, votes and snapshots.
+	fn close_proposal(id: &Identifier<T>, proposal_id: u64) {
+		Proposals::<T>::remove(id, proposal_id);
+		ProposalTallies::<T>::remove(id, proposal_id);
+		let _ = ProposalVotes::<T>::clear_prefix((id, proposal_id), u32::MAX, None);
+		let _ = ProposalSnapshots::<T>::clear_prefix((id, proposal_id), u32::MAX, None);
+		OpenProposals::<T>::mutate_exists(id, |open| {
+			*open = open.and_then(|open| open.checked_sub(1)).filter(|open| *open > 0);
+		});
+	}
+
+	/// The weight of removing every open proposal of an asset when it is destroyed.
+	///
+	/// Each proposal has a hash, a tally and a vote and a snapshot per holder.
+	pub(crate) fn proposals_cleanup_weight() -> Weight {
+		let entries = u64::from(T::MaxOpenProposals::get())
+			.saturating_mul(u64::from(T::MaxHolders::get()).saturating_mul(2).saturating_add(2));
+		T::DbWeight::get().writes(entries.saturating_add(1))
+	}
+}
+
+impl<T: Config> ProportionalAssetInspect<T::AccountId, Identifier<T>> for Pallet<T> {
+	fn main_owner(id: &Identifier<T>) -> Option<T::AccountId> {
+		Self::get_main_owner_by_asset(id)
+	}
+
+	fn shares_of(id: &Identifier<T>, who: &T::AccountId) -> u64 {
+		Pallet::<T>::shares_of(id, who)
+	}
+
+	fn is_owner(id: &Identifier<T>, who: &T::AccountId) -> bool {
+		ProportionalAssetToOwnerToMetadata::<T>::contains_key(id, who)
 	}
 
-	fn balance_to_u64_option(input: impl TryInto<u64>) -> Option<u64> {
-		input.try_into().ok()
+	fn total_owners(id: &Identifier<T>) -> u32 {
+		Self::owner_count(id)
 	}
 }
@@ -0,0 +1,385 @@
+//! Storage migrations for pallet-proportional-asset
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+	weights::Weight,
+};
+
+pub mod v1 {
+	use super::*;
+
+	/// The layout of the metadata before v1.
+	#[derive(Decode)]
+	pub struct OldMetaData {
+		pub offers: u64,
+		pub shares: u64,
+		pub price: u64,
+	}
+
+	/// The metadata of every owner in the v1 layout, with the price in plain units.
+	#[frame_support::storage_alias]
+	pub type ProportionalAssetToOwnerToMetadata<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		Identifier<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		MetaData<u64>,
+	>;
+
+	/// Translates the metadata of every owner into the v1 layout.
+	///
+	/// Entries without shares are removed, the offers are clamped to the shares held
+	/// and the owner count of every asset is populated from the remaining entries.
+	/// Main owners without a strict majority of the remaining shares are vacated.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut translated = 0u64;
+
+			ProportionalAssetToOwnerToMetadata::<T>::translate::<OldMetaData, _>(
+				|id, _who, old| {
+					translated = translated.saturating_add(1);
+
+					if old.shares == 0 {
+						return None;
+					}
+
+					ProportionalAssetToOwnerCount::<T>::mutate(id, |count| {
+						*count = count.saturating_add(1)
+					});
+
+					Some(MetaData {
+						shares: Shares(old.shares),
+						offers: Shares(old.offers).clamp_to(Shares(old.shares)),
+						price: old.price,
+					})
+				},
+			);
+
+			let mut main_owners = 0u64;
+			let stale: Vec<Identifier<T>> = ProportionalAssetToMainOwner::<T>::iter()
+				.filter(|(id, main_owner)| {
+					main_owners = main_owners.saturating_add(1);
+					let shares = ProportionalAssetToOwnerToMetadata::<T>::get(id, main_owner)
+						.map_or(0, |metadata| metadata.shares.0);
+					shares <= Pallet::<T>::effective_supply(id) / 2
+				})
+				.map(|(id, _)| id)
+				.collect();
+			for id in &stale {
+				ProportionalAssetToMainOwner::<T>::remove(id);
+			}
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(
+				translated
+					.saturating_mul(2)
+					.saturating_add(main_owners.saturating_mul(3))
+					.saturating_add(1),
+				translated
+					.saturating_mul(2)
+					.saturating_add(stale.len() as u64)
+					.saturating_add(1),
+			)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 0,
+				"The storage version should be 0 before the migration"
+			);
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"The storage version should be 1 after the migration"
+			);
+
+			for (id, _who, metadata) in ProportionalAssetToOwnerToMetadata::<T>::iter() {
+				ensure!(!metadata.shares.is_zero(), "Metadata without shares should be removed");
+				ensure!(metadata.offers <= metadata.shares, "Offers should not exceed the shares");
+				ensure!(
+					ProportionalAssetToOwnerCount::<T>::get(id)
+						== ProportionalAssetToOwnerToMetadata::<T>::iter_key_prefix(id).count()
+							as u32,
+					"The owner count should match the owners"
+				);
+			}
+			for (id, main_owner) in ProportionalAssetToMainOwner::<T>::iter() {
+				let shares = ProportionalAssetToOwnerToMetadata::<T>::get(id, main_owner)
+					.map_or(0, |metadata| metadata.shares.0);
+				ensure!(
+					shares > Pallet::<T>::effective_supply(&id) / 2,
+					"The main owner should hold a strict majority"
+				);
+			}
+
+			Ok(())
+		}
+	}
+}
+
+pub mod v2 {
+	use super::*;
+
+	/// Widens every stored price from plain units into the balance of the runtime.
+	///
+	/// The metadata of the owners, the orders, the bids, the price floors and the
+	/// private offers all stored their price as a `u64` before v2.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 1 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut translated = 0u64;
+
+			ProportionalAssetToOwnerToMetadata::<T>::translate_values::<MetaData<u64>, _>(|old| {
+				translated = translated.saturating_add(1);
+				Some(MetaData {
+					shares: old.shares,
+					offers: old.offers,
+					price: old.price.saturated_into(),
+				})
+			});
+			Orders::<T>::translate_values::<Order<T::AccountId, u64>, _>(|old| {
+				translated = translated.saturating_add(1);
+				Some(Order {
+					seller: old.seller,
+					shares: old.shares,
+					price: old.price.saturated_into(),
+				})
+			});
+			Bids::<T>::translate_values::<Bid<u64>, _>(|old| {
+				translated = translated.saturating_add(1);
+				Some(Bid { shares: old.shares, price: old.price.saturated_into() })
+			});
+			ProportionalAssetToPriceFloor::<T>::translate_values::<u64, _>(|old| {
+				translated = translated.saturating_add(1);
+				Some(old.saturated_into())
+			});
+			PrivateOffers::<T>::translate_values::<(u64, u64), _>(|(shares, price)| {
+				translated = translated.saturating_add(1);
+				Some((shares, price.saturated_into()))
+			});
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"The storage version should be 1 before the migration"
+			);
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 2,
+				"The storage version should be 2 after the migration"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v3 {
+	use super::*;
+
+	/// Moves the offer of every owner into the order book.
+	///
+	/// Each offer becomes an order of the same seller, shares and price under a freshly
+	/// allocated order id, the offer of the metadata is cleared. Expired offers could
+	/// never be bought anymore, so they are cleared without becoming orders.
+	///
+	/// Orders cannot be restricted to an allowlist or expire, so an offer with either is
+	/// left as an offer rather than opened to every buyer for good.
+	pub struct MigrateToV3<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 2 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let now = frame_system::Pallet::<T>::block_number();
+
+			// Collected first, the metadata cannot be written while iterating over it
+			let offers = ProportionalAssetToOwnerToMetadata::<T>::iter()
+				.filter(|(_, _, metadata)| !metadata.offers.is_zero())
+				.collect::<Vec<_>>();
+			let scanned = ProportionalAssetToOwnerToMetadata::<T>::iter_keys().count() as u64;
+
+			let mut next_order_id = NextOrderId::<T>::get();
+			for (id, who, metadata) in offers.iter().cloned() {
+				let expires_at = ProportionalAssetToOfferExpiry::<T>::get(id, &who);
+				let expired = expires_at.map_or(false, |expires_at| now > expires_at);
+
+				// A restricted offer that can still be bought stays an offer
+				if !expired
+					&& (expires_at.is_some()
+						|| ProportionalAssetToOfferAllowlist::<T>::contains_key(id, &who))
+				{
+					continue;
+				}
+
+				if expired {
+					ProportionalAssetToOfferExpiry::<T>::remove(id, &who);
+				} else {
+					let shares: u64 = metadata.offers.into();
+					Orders::<T>::insert(
+						id,
+						next_order_id,
+						Order { seller: who.clone(), shares, price: metadata.price },
+					);
+					next_order_id = next_order_id.saturating_add(1);
+					OrderedShares::<T>::mutate(id, &who, |ordered| {
+						*ordered = ordered.saturating_add(shares)
+					});
+				}
+
+				ProportionalAssetToOwnerToMetadata::<T>::insert(
+					id,
+					&who,
+					MetaData { offers: Shares(0), ..metadata },
+				);
+			}
+			NextOrderId::<T>::put(next_order_id);
+
+			StorageVersion::new(3).put::<Pallet<T>>();
+
+			let migrated = offers.len() as u64;
+			T::DbWeight::get().reads_writes(
+				scanned.saturating_add(migrated.saturating_mul(3)).saturating_add(2),
+				migrated.saturating_mul(4).saturating_add(2),
+			)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 2,
+				"The storage version should be 2 before the migration"
+			);
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 3,
+				"The storage version should be 3 after the migration"
+			);
+
+			for (id, who, metadata) in ProportionalAssetToOwnerToMetadata::<T>::iter() {
+				ensure!(
+					metadata.offers.is_zero()
+						|| ProportionalAssetToOfferExpiry::<T>::contains_key(id, &who)
+						|| ProportionalAssetToOfferAllowlist::<T>::contains_key(id, &who),
+					"The unrestricted offers should be moved to the order book"
+				);
+			}
+
+			Ok(())
+		}
+	}
+}
+
+pub mod v4 {
+	use super::*;
+
+	/// Backfills the indexes of the assets held and main owned by each account
+	/// and the amount of open proposals of each asset, after vacating the main owners
+	/// left without a strict majority by earlier versions.
+	///
+	/// `ProportionalAssetToOwnerReverseIndex`, `MainOwnerToAssets` and `OpenProposals`
+	/// were only written from the moment they were introduced, so the holdings, main owners
+	/// and proposals stored before then are missing from them.
+	pub struct MigrateToV4<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 3 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut indexed = 0u64;
+
+			let stale: Vec<Identifier<T>> = ProportionalAssetToMainOwner::<T>::iter()
+				.filter(|(id, main_owner)| {
+					indexed = indexed.saturating_add(1);
+					Pallet::<T>::shares_of(id, main_owner) <= Pallet::<T>::effective_supply(id) / 2
+				})
+				.map(|(id, _)| id)
+				.collect();
+			for id in &stale {
+				ProportionalAssetToMainOwner::<T>::remove(id);
+				indexed = indexed.saturating_add(1);
+			}
+
+			for (id, who) in ProportionalAssetToOwnerToMetadata::<T>::iter_keys() {
+				ProportionalAssetToOwnerReverseIndex::<T>::insert(who, id, ());
+				indexed = indexed.saturating_add(1);
+			}
+			for (id, main_owner) in ProportionalAssetToMainOwner::<T>::iter() {
+				MainOwnerToAssets::<T>::insert(main_owner, id, ());
+				indexed = indexed.saturating_add(1);
+			}
+			for (id, _) in Proposals::<T>::iter_keys() {
+				OpenProposals::<T>::mutate(id, |open| *open = open.saturating_add(1));
+				indexed = indexed.saturating_add(1);
+			}
+
+			StorageVersion::new(4).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(indexed.saturating_add(1), indexed.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 3,
+				"The storage version should be 3 before the migration"
+			);
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 4,
+				"The storage version should be 4 after the migration"
+			);
+
+			for (id, who) in ProportionalAssetToOwnerToMetadata::<T>::iter_keys() {
+				ensure!(
+					ProportionalAssetToOwnerReverseIndex::<T>::contains_key(who, id),
+					"Every holding should be in the reverse index"
+				);
+			}
+
+			Pallet::<T>::do_try_state()
+		}
+	}
+}
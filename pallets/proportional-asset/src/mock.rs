@@ -1,15 +1,16 @@
 use crate as pallet_proportional_asset;
+use crate::Rounding;
 
 use frame_support::{
 	parameter_types,
-	traits::{ConstU16, ConstU64},
+	traits::{ConstU16, ConstU32, ConstU64},
 };
 use frame_system as system;
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage,
+	BuildStorage, Permill,
 };
 
 pub(crate) type Balance = u128;
@@ -19,6 +20,42 @@ type Block = frame_system::mocking::MockBlock<Test>;
 
 parameter_types! {
 	pub static ExistentialDeposit: Balance = 1;
+	pub static TotalSupply: u64 = 100;
+	pub static AssetDeposit: Balance = 0;
+	pub static MaxHolders: u32 = 10;
+	pub static MaxAuctionBidders: u32 = 10;
+	pub static MaxOpenProposals: u32 = 3;
+	pub static MaxAuctionSettlements: u32 = 10;
+	pub static MaxPriceHistory: u32 = 3;
+	pub static MinHolding: u64 = 1;
+	pub static MinShareTransfer: u64 = 1;
+	pub static MaxHoldingPercent: Permill = Permill::one();
+	pub static MainOwnerExemptFromCap: bool = false;
+	pub static RoundingPolicy: Rounding = Rounding::LargestRemainder;
+	pub static SaltedAssetIds: bool = false;
+	pub static ShareDecimals: u32 = 0;
+	pub static ClearOffersOnTransfer: bool = false;
+	pub static MarketplaceFee: Permill = Permill::zero();
+	pub static MaxSharePrice: Balance = Balance::MAX / 100;
+	pub static MaxPriceChange: Permill = Permill::zero();
+	pub static MinReofferInterval: u64 = 0;
+	pub static MainOwnerChanges: Vec<(Option<u64>, u64, H256)> = vec![];
+	pub static ObservedAsset: Option<H256> = None;
+	pub static ObservedShares: Vec<(u64, u64)> = vec![];
+	pub const FeeCollector: u64 = 99;
+}
+
+// Calls back into the pallet whenever the currency creates an account,
+// recording the shares the new account holds of the observed asset.
+pub struct ObserveSharesOnNewAccount;
+impl frame_support::traits::OnNewAccount<u64> for ObserveSharesOnNewAccount {
+	fn on_new_account(who: &u64) {
+		if let Some(id) = ObservedAsset::get() {
+			let mut observed = ObservedShares::get();
+			observed.push((*who, ProportionalAssetModule::shares_of(&id, who)));
+			ObservedShares::set(observed);
+		}
+	}
 }
 
 // Configure a mock runtime to test the pallet.
@@ -65,7 +102,7 @@ impl system::Config for Test {
 	type Version = ();
 	type PalletInfo = PalletInfo;
 	type AccountData = pallet_balances::AccountData<Balance>;
-	type OnNewAccount = ();
+	type OnNewAccount = ObserveSharesOnNewAccount;
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
 	type SS58Prefix = ConstU16<42>;
@@ -76,10 +113,48 @@ impl system::Config for Test {
 impl pallet_proportional_asset::Config for Test {
 	type Event = Event;
 	type Currency = Balances;
+	type TotalSupply = TotalSupply;
+	type MaxDataLength = ConstU32<64>;
+	type AssetDeposit = AssetDeposit;
+	type MaxAllowlistLength = ConstU32<10>;
+	type WeightInfo = ();
+	type MaxBatchSize = ConstU32<5>;
+	type MaxNameLength = ConstU32<16>;
+	type MaxUriLength = ConstU32<32>;
+	type MaxHolders = MaxHolders;
+	type MaxAuctionBidders = MaxAuctionBidders;
+	type MaxAuctionSettlements = MaxAuctionSettlements;
+	type MinHolding = MinHolding;
+	type MinShareTransfer = MinShareTransfer;
+	type MaxHoldingPercent = MaxHoldingPercent;
+	type MainOwnerExemptFromCap = MainOwnerExemptFromCap;
+	type RoundingPolicy = RoundingPolicy;
+	type SaltedAssetIds = SaltedAssetIds;
+	type ShareDecimals = ShareDecimals;
+	type ClearOffersOnTransfer = ClearOffersOnTransfer;
+	type MarketplaceFee = MarketplaceFee;
+	type MaxSharePrice = MaxSharePrice;
+	type FeeCollector = FeeCollector;
+	type MaxPriceChange = MaxPriceChange;
+	type MinReofferInterval = MinReofferInterval;
+	type OnMainOwnerChanged = RecordMainOwnerChanges;
+	type MaxPriceHistory = MaxPriceHistory;
+	type MaxOpenProposals = MaxOpenProposals;
+}
+
+/// Records the main owner changes notified by the pallet.
+pub struct RecordMainOwnerChanges;
+
+impl pallet_proportional_asset::OnMainOwnerChanged<u64, H256> for RecordMainOwnerChanges {
+	fn on_changed(old: Option<u64>, new: u64, id: H256) {
+		let mut changes = MainOwnerChanges::get();
+		changes.push((old, new, id));
+		MainOwnerChanges::set(changes);
+	}
 }
 
 pub fn get_initial_balances() -> Vec<(u64, u128)> {
-	vec![(1, 50), (2, 50)]
+	vec![(1, 50), (2, 50), (3, 1_000)]
 }
 
 pub fn get_test_data() -> Vec<u8> {
@@ -94,13 +169,24 @@ pub fn get_hash_from_vec(data: Vec<u8>) -> H256 {
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
+	new_test_ext_with_assets(vec![])
+}
+
+// Build genesis storage with preloaded proportional assets.
+pub fn new_test_ext_with_assets(
+	assets: Vec<(Vec<u8>, Vec<(u64, u64, Balance)>)>,
+) -> sp_io::TestExternalities {
 	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
 
 	let initial_balances = get_initial_balances();
 
-	GenesisConfig { balances: BalancesConfig { balances: initial_balances }, ..Default::default() }
-		.assimilate_storage(&mut t)
-		.unwrap();
+	GenesisConfig {
+		balances: BalancesConfig { balances: initial_balances },
+		proportional_asset_module: ProportionalAssetModuleConfig { assets },
+		..Default::default()
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
 
 	let mut ext = sp_io::TestExternalities::new(t);
 	ext.execute_with(|| System::set_block_number(1));
@@ -0,0 +1,74 @@
+//! A quantity of shares of a proportional asset.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// An amount of shares, kept apart from the prices and the other `u64` values.
+///
+/// It is encoded exactly like a `u64`, the calls keep taking plain `u64` arguments
+/// that are converted when they reach the metadata.
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	RuntimeDebug,
+	Default,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct Shares(pub u64);
+
+impl Shares {
+	/// No shares at all.
+	pub const fn zero() -> Self {
+		Shares(0)
+	}
+
+	/// Whether there are no shares.
+	pub fn is_zero(&self) -> bool {
+		self.0 == 0
+	}
+
+	/// Adds shares, stopping at the largest amount instead of overflowing.
+	pub fn saturating_add(self, other: Shares) -> Shares {
+		Shares(self.0.saturating_add(other.0))
+	}
+
+	/// Subtracts shares, stopping at zero instead of underflowing.
+	pub fn saturating_sub(self, other: Shares) -> Shares {
+		Shares(self.0.saturating_sub(other.0))
+	}
+
+	/// Adds shares, `None` if the amount overflows.
+	pub fn checked_add(self, other: Shares) -> Option<Shares> {
+		self.0.checked_add(other.0).map(Shares)
+	}
+
+	/// Subtracts shares, `None` if the amount underflows.
+	pub fn checked_sub(self, other: Shares) -> Option<Shares> {
+		self.0.checked_sub(other.0).map(Shares)
+	}
+
+	/// The shares capped at `max`, e.g. the offers of an owner capped at the shares held.
+	pub fn clamp_to(self, max: Shares) -> Shares {
+		Shares(self.0.min(max.0))
+	}
+}
+
+impl From<u64> for Shares {
+	fn from(amount: u64) -> Self {
+		Shares(amount)
+	}
+}
+
+impl From<Shares> for u64 {
+	fn from(shares: Shares) -> Self {
+		shares.0
+	}
+}
@@ -1,5 +1,19 @@
-use crate::{mock::*, Error, ProportionalAssetToOwnerToMetadata, TOTAL_SUPPLY};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	mock::*, AssetInfo, AssetStatus, AuctionBids, Auctions, Bids, CreatorNonce, Error,
+	MainOwnerToAssets, NextAuctionSettlement, OpenProposals, PrivateOffers,
+	ProportionalAssetAllowance, ProportionalAssetInspect, ProportionalAssetToBurnedShares,
+	ProportionalAssetToDeposit, ProportionalAssetToMainOwner, ProportionalAssetToOwnerToMetadata,
+	ProportionalAssetToPriceFloor, ProposalTallies, Proposals, Rounding, Shares, SwapIntent,
+	SwapIntents, Tally,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::{DispatchErrorWithPostInfo, DispatchResult},
+	traits::Hooks,
+	weights::Pays,
+};
+use sp_core::H256;
+use sp_runtime::{traits::BadOrigin, Permill};
 
 #[test]
 fn create_proportional_asset_success() {
@@ -11,13 +25,16 @@ fn create_proportional_asset_success() {
 		assert_ok!(ProportionalAssetModule::create_proportional_asset(
 			Origin::signed(1),
 			data.clone(),
-			share_price
+			share_price,
+			None,
+			None
 		));
 
 		let id = get_hash_from_vec(data);
 
-		let expected_event =
-			Event::ProportionalAssetModule(crate::Event::ProportionalAssetInitialized(id, 1));
+		let expected_event = Event::ProportionalAssetModule(
+			crate::Event::ProportionalAssetInitialized(id, 1, 100, share_price),
+		);
 		assert_eq!(System::events()[0].event, expected_event);
 
 		let owner_id = ProportionalAssetModule::get_main_owner_by_asset(&id).unwrap();
@@ -25,12 +42,84 @@ fn create_proportional_asset_success() {
 
 		let owner_metadata = ProportionalAssetToOwnerToMetadata::<Test>::get(id, owner_id).unwrap();
 
-		assert_eq!(owner_metadata.offers, 0);
-		assert_eq!(owner_metadata.shares, TOTAL_SUPPLY);
+		assert_eq!(owner_metadata.offers, Shares(0));
+		assert_eq!(owner_metadata.shares, Shares(TotalSupply::get()));
 		assert_eq!(owner_metadata.price, share_price);
 	});
 }
 
+#[test]
+fn create_proportional_asset_success_custom_total_supply() {
+	new_test_ext().execute_with(|| {
+		TotalSupply::set(10_000);
+
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			10,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(data);
+
+		let owner_metadata = ProportionalAssetToOwnerToMetadata::<Test>::get(id, &1).unwrap();
+		assert_eq!(owner_metadata.shares, Shares(10_000));
+	});
+}
+
+#[test]
+fn create_proportional_asset_success_max_data_length() {
+	new_test_ext().execute_with(|| {
+		let data = vec![1u8; 64];
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			10,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(data);
+		assert!(ProportionalAssetToOwnerToMetadata::<Test>::contains_key(id, &1));
+	});
+}
+
+#[test]
+fn create_proportional_asset_failure_empty_data() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ProportionalAssetModule::create_proportional_asset(
+				Origin::signed(1),
+				vec![],
+				10,
+				None,
+				None
+			),
+			Error::<Test>::EmptyAssetData
+		);
+	});
+}
+
+#[test]
+fn create_proportional_asset_failure_data_too_long() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ProportionalAssetModule::create_proportional_asset(
+				Origin::signed(1),
+				vec![1u8; 65],
+				10,
+				None,
+				None
+			),
+			Error::<Test>::AssetDataTooLong
+		);
+	});
+}
+
 #[test]
 fn create_proportional_asset_failure_duplicate() {
 	new_test_ext().execute_with(|| {
@@ -41,14 +130,18 @@ fn create_proportional_asset_failure_duplicate() {
 		assert_ok!(ProportionalAssetModule::create_proportional_asset(
 			Origin::signed(1),
 			data.clone(),
-			share_price
+			share_price,
+			None,
+			None
 		));
 
 		assert_noop!(
 			ProportionalAssetModule::create_proportional_asset(
 				Origin::signed(1),
 				data,
-				share_price
+				share_price,
+				None,
+				None
 			),
 			Error::<Test>::AssetAlreadyExists
 		);
@@ -65,7 +158,9 @@ fn offer_shares_success() {
 		assert_ok!(ProportionalAssetModule::create_proportional_asset(
 			Origin::signed(1),
 			data.clone(),
-			share_price
+			share_price,
+			None,
+			None
 		));
 
 		let id = get_hash_from_vec(data);
@@ -77,20 +172,49 @@ fn offer_shares_success() {
 			Origin::signed(1),
 			id,
 			offers,
-			new_share_price
+			new_share_price,
+			None
 		));
 
-		let expected_event =
-			Event::ProportionalAssetModule(crate::Event::SharesOffered(id, new_share_price));
+		let expected_event = Event::ProportionalAssetModule(crate::Event::SharesOffered {
+			id,
+			seller: 1,
+			shares: offers,
+			price: new_share_price,
+		});
 		assert_eq!(System::events()[1].event, expected_event);
 
 		let stored_metadata = ProportionalAssetToOwnerToMetadata::<Test>::get(id, &1).unwrap();
 
-		assert_eq!(stored_metadata.offers, offers);
+		assert_eq!(stored_metadata.offers, Shares(offers));
 		assert_eq!(new_share_price, stored_metadata.price);
 	})
 }
 
+#[test]
+fn active_offer_returns_the_offer_of_an_owner() {
+	new_test_ext().execute_with(|| {
+		let data = get_test_data();
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			10,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+		assert_eq!(ProportionalAssetModule::active_offer(&id, &1), None);
+		assert_eq!(ProportionalAssetModule::active_offer(&id, &2), None);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 5, 20, Some(3)));
+		assert_eq!(ProportionalAssetModule::active_offer(&id, &1), Some((5, 20)));
+
+		// The offer is not active once expired
+		System::set_block_number(4);
+		assert_eq!(ProportionalAssetModule::active_offer(&id, &1), None);
+	})
+}
+
 #[test]
 fn offer_shares_failure_invalid_offers() {
 	new_test_ext().execute_with(|| {
@@ -101,7 +225,9 @@ fn offer_shares_failure_invalid_offers() {
 		assert_ok!(ProportionalAssetModule::create_proportional_asset(
 			Origin::signed(1),
 			data.clone(),
-			share_price
+			share_price,
+			None,
+			None
 		));
 
 		let id = get_hash_from_vec(data);
@@ -110,8 +236,14 @@ fn offer_shares_failure_invalid_offers() {
 
 		let new_share_price = 20;
 		assert_noop!(
-			ProportionalAssetModule::offer_shares(Origin::signed(1), id, offers, new_share_price),
-			Error::<Test>::InvalidOffers
+			ProportionalAssetModule::offer_shares(
+				Origin::signed(1),
+				id,
+				offers,
+				new_share_price,
+				None
+			),
+			Error::<Test>::OverCommittedOffers
 		);
 	})
 }
@@ -126,7 +258,9 @@ fn offer_shares_failure_different_account() {
 		assert_ok!(ProportionalAssetModule::create_proportional_asset(
 			Origin::signed(1),
 			data.clone(),
-			share_price
+			share_price,
+			None,
+			None
 		));
 
 		let id = get_hash_from_vec(data);
@@ -135,12 +269,61 @@ fn offer_shares_failure_different_account() {
 
 		let new_share_price = 20;
 		assert_noop!(
-			ProportionalAssetModule::offer_shares(Origin::signed(2), id, offers, new_share_price),
-			Error::<Test>::NotMainOwner
+			ProportionalAssetModule::offer_shares(
+				Origin::signed(2),
+				id,
+				offers,
+				new_share_price,
+				None
+			),
+			Error::<Test>::NotAnOwner
 		);
 	})
 }
 
+#[test]
+fn offer_shares_success_minority_owner() {
+	new_test_ext().execute_with(|| {
+		let share_price = 10;
+
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			share_price,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			30,
+			2
+		));
+
+		let offers = 10;
+
+		let new_share_price = 20;
+		assert_ok!(ProportionalAssetModule::offer_shares(
+			Origin::signed(2),
+			id,
+			offers,
+			new_share_price,
+			None
+		));
+
+		let stored_metadata = ProportionalAssetToOwnerToMetadata::<Test>::get(id, &2).unwrap();
+
+		assert_eq!(stored_metadata.shares, Shares(30));
+		assert_eq!(stored_metadata.offers, Shares(offers));
+		assert_eq!(stored_metadata.price, new_share_price);
+	})
+}
+
 #[test]
 fn transfer_shares_to_account_success() {
 	new_test_ext().execute_with(|| {
@@ -151,7 +334,9 @@ fn transfer_shares_to_account_success() {
 		assert_ok!(ProportionalAssetModule::create_proportional_asset(
 			Origin::signed(1),
 			data.clone(),
-			share_price
+			share_price,
+			None,
+			None
 		));
 
 		let id = get_hash_from_vec(data);
@@ -166,14 +351,14 @@ fn transfer_shares_to_account_success() {
 		));
 
 		let expected_event =
-			Event::ProportionalAssetModule(crate::Event::SharesTransferred(1, 2, amount));
+			Event::ProportionalAssetModule(crate::Event::SharesTransferred(id, 1, 2, amount));
 		assert_eq!(System::events()[1].event, expected_event);
 
 		let stored_metadata_1 = ProportionalAssetToOwnerToMetadata::<Test>::get(id, &1).unwrap();
-		assert_eq!(stored_metadata_1.shares, 100 - amount);
+		assert_eq!(stored_metadata_1.shares, Shares(100 - amount));
 
 		let stored_metadata_2 = ProportionalAssetToOwnerToMetadata::<Test>::get(id, &2).unwrap();
-		assert_eq!(stored_metadata_2.shares, amount);
+		assert_eq!(stored_metadata_2.shares, Shares(amount));
 	})
 }
 
@@ -187,7 +372,9 @@ fn transfer_shares_to_account_failure_invalid_account() {
 		assert_ok!(ProportionalAssetModule::create_proportional_asset(
 			Origin::signed(1),
 			data.clone(),
-			share_price
+			share_price,
+			None,
+			None
 		));
 
 		let id = get_hash_from_vec(data);
@@ -211,7 +398,9 @@ fn transfer_shares_to_account_failure_incorrect_share_selection() {
 		assert_ok!(ProportionalAssetModule::create_proportional_asset(
 			Origin::signed(1),
 			data.clone(),
-			share_price
+			share_price,
+			None,
+			None
 		));
 
 		let id = get_hash_from_vec(data);
@@ -225,6 +414,116 @@ fn transfer_shares_to_account_failure_incorrect_share_selection() {
 	})
 }
 
+#[test]
+fn transfer_shares_to_account_failure_transfer_to_self() {
+	new_test_ext().execute_with(|| {
+		let share_price = 10;
+
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			share_price,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(data);
+
+		assert_noop!(
+			ProportionalAssetModule::transfer_shares_to_account(Origin::signed(1), id, 50, 1),
+			Error::<Test>::CannotTransferToSelf
+		);
+	})
+}
+
+#[test]
+fn transfer_shares_to_account_preserves_total_supply() {
+	new_test_ext().execute_with(|| {
+		let share_price = 10;
+
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			share_price,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			40,
+			2
+		));
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(2),
+			id,
+			15,
+			3
+		));
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(3),
+			id,
+			5,
+			1
+		));
+
+		let total_shares: u64 = ProportionalAssetToOwnerToMetadata::<Test>::iter_prefix_values(id)
+			.map(|metadata| u64::from(metadata.shares))
+			.sum();
+		assert_eq!(total_shares, TotalSupply::get());
+	})
+}
+
+#[test]
+fn transfer_shares_to_account_clamps_offers() {
+	new_test_ext().execute_with(|| {
+		let share_price = 10;
+
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			share_price,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(
+			Origin::signed(1),
+			id,
+			TotalSupply::get(),
+			share_price,
+			None
+		));
+
+		let amount = 30;
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			amount,
+			2
+		));
+
+		let stored_metadata_1 = ProportionalAssetToOwnerToMetadata::<Test>::get(id, &1).unwrap();
+		assert_eq!(stored_metadata_1.shares, Shares(TotalSupply::get() - amount));
+		assert_eq!(stored_metadata_1.offers, Shares(TotalSupply::get() - amount));
+
+		let stored_metadata_2 = ProportionalAssetToOwnerToMetadata::<Test>::get(id, &2).unwrap();
+		assert!(stored_metadata_2.offers <= stored_metadata_2.shares);
+	})
+}
+
 #[test]
 fn buy_shares_success() {
 	new_test_ext().execute_with(|| {
@@ -235,7 +534,9 @@ fn buy_shares_success() {
 		assert_ok!(ProportionalAssetModule::create_proportional_asset(
 			Origin::signed(1),
 			data.clone(),
-			share_price
+			share_price,
+			None,
+			None
 		));
 
 		let id = get_hash_from_vec(data);
@@ -247,29 +548,40 @@ fn buy_shares_success() {
 			Origin::signed(1),
 			id,
 			offers,
-			new_share_price
+			new_share_price,
+			None
 		));
 
-		let expected_event =
-			Event::ProportionalAssetModule(crate::Event::SharesOffered(id, new_share_price));
+		let expected_event = Event::ProportionalAssetModule(crate::Event::SharesOffered {
+			id,
+			seller: 1,
+			shares: offers,
+			price: new_share_price,
+		});
 		assert_eq!(System::events()[1].event, expected_event);
 
 		let stored_metadata = ProportionalAssetToOwnerToMetadata::<Test>::get(id, &1).unwrap();
-		assert_eq!(stored_metadata.offers, offers);
+		assert_eq!(stored_metadata.offers, Shares(offers));
 		assert_eq!(stored_metadata.price, new_share_price);
 
 		let shares_to_buy = 2;
-		let amount_to_be_transferred = new_share_price.checked_mul(shares_to_buy).unwrap();
+		let amount_to_be_transferred = new_share_price.checked_mul(shares_to_buy.into()).unwrap();
 		assert_ok!(ProportionalAssetModule::buy_shares(
 			Origin::signed(2),
 			id,
 			shares_to_buy,
 			amount_to_be_transferred.into(),
-			1
+			1,
+			Balance::MAX
 		));
 
-		let expected_event_pallet =
-			Event::ProportionalAssetModule(crate::Event::SharesTransferred(1, 2, shares_to_buy));
+		let expected_event_pallet = Event::ProportionalAssetModule(crate::Event::SharesBought {
+			id,
+			buyer: 2,
+			seller: 1,
+			shares: shares_to_buy,
+			price_paid: amount_to_be_transferred.into(),
+		});
 		assert_eq!(System::events()[3].event, expected_event_pallet);
 
 		let initial_balances = get_initial_balances();
@@ -291,8 +603,8 @@ fn buy_shares_success() {
 		let stored_metadata_1 = ProportionalAssetToOwnerToMetadata::<Test>::get(id, &1).unwrap();
 		let stored_metadata_2 = ProportionalAssetToOwnerToMetadata::<Test>::get(id, &2).unwrap();
 
-		assert_eq!(stored_metadata_1.shares, 100 - shares_to_buy);
-		assert_eq!(stored_metadata_2.shares, shares_to_buy);
+		assert_eq!(stored_metadata_1.shares, Shares(100 - shares_to_buy));
+		assert_eq!(stored_metadata_2.shares, Shares(shares_to_buy));
 
 		// TODO: Check offers
 	})
@@ -308,7 +620,9 @@ fn buy_shares_failure_incorrect_seller_same_owner() {
 		assert_ok!(ProportionalAssetModule::create_proportional_asset(
 			Origin::signed(1),
 			data.clone(),
-			share_price
+			share_price,
+			None,
+			None
 		));
 
 		let id = get_hash_from_vec(data);
@@ -320,26 +634,32 @@ fn buy_shares_failure_incorrect_seller_same_owner() {
 			Origin::signed(1),
 			id,
 			offers,
-			new_share_price
+			new_share_price,
+			None
 		));
 
-		let expected_event =
-			Event::ProportionalAssetModule(crate::Event::SharesOffered(id, new_share_price));
+		let expected_event = Event::ProportionalAssetModule(crate::Event::SharesOffered {
+			id,
+			seller: 1,
+			shares: offers,
+			price: new_share_price,
+		});
 		assert_eq!(System::events()[1].event, expected_event);
 
 		let stored_metadata = ProportionalAssetToOwnerToMetadata::<Test>::get(id, &1).unwrap();
-		assert_eq!(stored_metadata.offers, offers);
+		assert_eq!(stored_metadata.offers, Shares(offers));
 		assert_eq!(stored_metadata.price, new_share_price);
 
 		let shares_to_buy = 2;
-		let amount_to_be_transferred = new_share_price.checked_mul(shares_to_buy).unwrap();
+		let amount_to_be_transferred = new_share_price.checked_mul(shares_to_buy.into()).unwrap();
 		assert_noop!(
 			ProportionalAssetModule::buy_shares(
 				Origin::signed(1),
 				id,
 				shares_to_buy,
 				amount_to_be_transferred.into(),
-				1
+				1,
+				Balance::MAX
 			),
 			Error::<Test>::IncorrectSeller
 		);
@@ -347,7 +667,7 @@ fn buy_shares_failure_incorrect_seller_same_owner() {
 }
 
 #[test]
-fn buy_shares_failure_incorrect_seller_not_owner() {
+fn buy_shares_failure_asset_does_not_exist() {
 	new_test_ext().execute_with(|| {
 		let share_price = 10;
 
@@ -356,7 +676,9 @@ fn buy_shares_failure_incorrect_seller_not_owner() {
 		assert_ok!(ProportionalAssetModule::create_proportional_asset(
 			Origin::signed(1),
 			data.clone(),
-			share_price
+			share_price,
+			None,
+			None
 		));
 
 		let id = get_hash_from_vec(data);
@@ -368,19 +690,24 @@ fn buy_shares_failure_incorrect_seller_not_owner() {
 			Origin::signed(1),
 			id,
 			offers,
-			new_share_price
+			new_share_price,
+			None
 		));
 
-		let expected_event =
-			Event::ProportionalAssetModule(crate::Event::SharesOffered(id, new_share_price));
+		let expected_event = Event::ProportionalAssetModule(crate::Event::SharesOffered {
+			id,
+			seller: 1,
+			shares: offers,
+			price: new_share_price,
+		});
 		assert_eq!(System::events()[1].event, expected_event);
 
 		let stored_metadata = ProportionalAssetToOwnerToMetadata::<Test>::get(id, &1).unwrap();
-		assert_eq!(stored_metadata.offers, offers);
+		assert_eq!(stored_metadata.offers, Shares(offers));
 		assert_eq!(stored_metadata.price, new_share_price);
 
 		let shares_to_buy = 2;
-		let amount_to_be_transferred = new_share_price.checked_mul(shares_to_buy).unwrap();
+		let amount_to_be_transferred = new_share_price.checked_mul(shares_to_buy.into()).unwrap();
 
 		let src1: Vec<char> = vec!['a', 'b', '"', 'i', 'm', 'm', 'y', '"', '}'];
 		let data: Vec<u8> = src1.iter().map(|c| *c as u8).collect::<Vec<_>>();
@@ -391,9 +718,31 @@ fn buy_shares_failure_incorrect_seller_not_owner() {
 				id,
 				shares_to_buy,
 				amount_to_be_transferred.into(),
-				1
+				1,
+				Balance::MAX
 			),
-			Error::<Test>::IncorrectSeller
+			Error::<Test>::AssetDoesNotExist
+		);
+	})
+}
+
+#[test]
+fn transfer_shares_to_account_failure_asset_does_not_exist() {
+	new_test_ext().execute_with(|| {
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data,
+			10,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(vec![4, 5, 6]);
+		assert_noop!(
+			ProportionalAssetModule::transfer_shares_to_account(Origin::signed(1), id, 10, 2),
+			Error::<Test>::AssetDoesNotExist
 		);
 	})
 }
@@ -408,7 +757,9 @@ fn buy_shares_failure_insufficient_balance() {
 		assert_ok!(ProportionalAssetModule::create_proportional_asset(
 			Origin::signed(1),
 			data.clone(),
-			share_price
+			share_price,
+			None,
+			None
 		));
 
 		let id = get_hash_from_vec(data);
@@ -420,26 +771,32 @@ fn buy_shares_failure_insufficient_balance() {
 			Origin::signed(1),
 			id,
 			offers,
-			new_share_price
+			new_share_price,
+			None
 		));
 
-		let expected_event =
-			Event::ProportionalAssetModule(crate::Event::SharesOffered(id, new_share_price));
+		let expected_event = Event::ProportionalAssetModule(crate::Event::SharesOffered {
+			id,
+			seller: 1,
+			shares: offers,
+			price: new_share_price,
+		});
 		assert_eq!(System::events()[1].event, expected_event);
 
 		let stored_metadata = ProportionalAssetToOwnerToMetadata::<Test>::get(id, &1).unwrap();
-		assert_eq!(stored_metadata.offers, offers);
+		assert_eq!(stored_metadata.offers, Shares(offers));
 		assert_eq!(stored_metadata.price, new_share_price);
 
 		let shares_to_buy = 2;
-		let amount_to_be_transferred = new_share_price.checked_mul(shares_to_buy).unwrap();
+		let amount_to_be_transferred = new_share_price.checked_mul(shares_to_buy.into()).unwrap();
 		assert_noop!(
 			ProportionalAssetModule::buy_shares(
 				Origin::signed(2),
 				id,
 				shares_to_buy,
 				amount_to_be_transferred.into(),
-				1
+				1,
+				Balance::MAX
 			),
 			Error::<Test>::InsufficientBalance
 		);
@@ -456,7 +813,9 @@ fn claim_onwership_success() {
 		assert_ok!(ProportionalAssetModule::create_proportional_asset(
 			Origin::signed(1),
 			data.clone(),
-			share_price
+			share_price,
+			None,
+			None
 		));
 
 		let id = get_hash_from_vec(data);
@@ -471,20 +830,24 @@ fn claim_onwership_success() {
 		));
 
 		let expected_event =
-			Event::ProportionalAssetModule(crate::Event::SharesTransferred(1, 2, amount));
+			Event::ProportionalAssetModule(crate::Event::SharesTransferred(id, 1, 2, amount));
 		assert_eq!(System::events()[1].event, expected_event);
 
 		let stored_metadata_1 = ProportionalAssetToOwnerToMetadata::<Test>::get(id, &1).unwrap();
-		assert_eq!(stored_metadata_1.shares, 100 - amount);
+		assert_eq!(stored_metadata_1.shares, Shares(100 - amount));
 
 		let stored_metadata_2 = ProportionalAssetToOwnerToMetadata::<Test>::get(id, &2).unwrap();
-		assert_eq!(stored_metadata_2.shares, amount);
+		assert_eq!(stored_metadata_2.shares, Shares(amount));
+
+		let expected_vacated_event =
+			Event::ProportionalAssetModule(crate::Event::MainOwnerVacated(id));
+		assert_eq!(System::events()[2].event, expected_vacated_event);
 
 		assert_ok!(ProportionalAssetModule::claim_onwership(Origin::signed(2), id));
 
 		let expected_main_owner_event =
 			Event::ProportionalAssetModule(crate::Event::MainOwnerSet(2, id));
-		assert_eq!(System::events()[2].event, expected_main_owner_event);
+		assert_eq!(System::events()[3].event, expected_main_owner_event);
 
 		let is_owner = ProportionalAssetModule::is_owner_of(&2, &id);
 		assert!(is_owner);
@@ -501,7 +864,9 @@ fn claim_onwership_failure_asset_does_not_exist() {
 		assert_ok!(ProportionalAssetModule::create_proportional_asset(
 			Origin::signed(1),
 			data.clone(),
-			share_price
+			share_price,
+			None,
+			None
 		));
 
 		let id = get_hash_from_vec(data);
@@ -516,14 +881,14 @@ fn claim_onwership_failure_asset_does_not_exist() {
 		));
 
 		let expected_event =
-			Event::ProportionalAssetModule(crate::Event::SharesTransferred(1, 2, amount));
+			Event::ProportionalAssetModule(crate::Event::SharesTransferred(id, 1, 2, amount));
 		assert_eq!(System::events()[1].event, expected_event);
 
 		let stored_metadata_1 = ProportionalAssetToOwnerToMetadata::<Test>::get(id, &1).unwrap();
-		assert_eq!(stored_metadata_1.shares, 100 - amount);
+		assert_eq!(stored_metadata_1.shares, Shares(100 - amount));
 
 		let stored_metadata_2 = ProportionalAssetToOwnerToMetadata::<Test>::get(id, &2).unwrap();
-		assert_eq!(stored_metadata_2.shares, amount);
+		assert_eq!(stored_metadata_2.shares, Shares(amount));
 
 		let src1: Vec<char> = vec!['a', 'b', '"', 'i', 'm', 'm', 'y', '"', '}'];
 		let data: Vec<u8> = src1.iter().map(|c| *c as u8).collect::<Vec<_>>();
@@ -547,12 +912,14 @@ fn claim_onwership_failure_already_main_owner() {
 		assert_ok!(ProportionalAssetModule::create_proportional_asset(
 			Origin::signed(1),
 			data.clone(),
-			share_price
+			share_price,
+			None,
+			None
 		));
 
 		let id = get_hash_from_vec(data);
 
-		let amount = 51;
+		let amount = 49;
 
 		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
 			Origin::signed(1),
@@ -562,14 +929,14 @@ fn claim_onwership_failure_already_main_owner() {
 		));
 
 		let expected_event =
-			Event::ProportionalAssetModule(crate::Event::SharesTransferred(1, 2, amount));
+			Event::ProportionalAssetModule(crate::Event::SharesTransferred(id, 1, 2, amount));
 		assert_eq!(System::events()[1].event, expected_event);
 
 		let stored_metadata_1 = ProportionalAssetToOwnerToMetadata::<Test>::get(id, &1).unwrap();
-		assert_eq!(stored_metadata_1.shares, 100 - amount);
+		assert_eq!(stored_metadata_1.shares, Shares(100 - amount));
 
 		let stored_metadata_2 = ProportionalAssetToOwnerToMetadata::<Test>::get(id, &2).unwrap();
-		assert_eq!(stored_metadata_2.shares, amount);
+		assert_eq!(stored_metadata_2.shares, Shares(amount));
 
 		assert_noop!(
 			ProportionalAssetModule::claim_onwership(Origin::signed(1), id),
@@ -588,7 +955,9 @@ fn claim_onwership_failure_not_enough_shares() {
 		assert_ok!(ProportionalAssetModule::create_proportional_asset(
 			Origin::signed(1),
 			data.clone(),
-			share_price
+			share_price,
+			None,
+			None
 		));
 
 		let id = get_hash_from_vec(data);
@@ -603,14 +972,14 @@ fn claim_onwership_failure_not_enough_shares() {
 		));
 
 		let expected_event =
-			Event::ProportionalAssetModule(crate::Event::SharesTransferred(1, 2, amount));
+			Event::ProportionalAssetModule(crate::Event::SharesTransferred(id, 1, 2, amount));
 		assert_eq!(System::events()[1].event, expected_event);
 
 		let stored_metadata_1 = ProportionalAssetToOwnerToMetadata::<Test>::get(id, &1).unwrap();
-		assert_eq!(stored_metadata_1.shares, 100 - amount);
+		assert_eq!(stored_metadata_1.shares, Shares(100 - amount));
 
 		let stored_metadata_2 = ProportionalAssetToOwnerToMetadata::<Test>::get(id, &2).unwrap();
-		assert_eq!(stored_metadata_2.shares, amount);
+		assert_eq!(stored_metadata_2.shares, Shares(amount));
 
 		assert_noop!(
 			ProportionalAssetModule::claim_onwership(Origin::signed(2), id),
@@ -618,3 +987,5906 @@ fn claim_onwership_failure_not_enough_shares() {
 		);
 	})
 }
+
+#[test]
+fn owners_of_success() {
+	new_test_ext().execute_with(|| {
+		let share_price = 10;
+
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			share_price,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			30,
+			3
+		));
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			20,
+			2
+		));
+
+		let owners = ProportionalAssetModule::owners_of(&id);
+		assert_eq!(owners.len(), 3);
+
+		let owner_shares: Vec<(u64, u64)> = owners
+			.into_iter()
+			.map(|(who, metadata)| (who, metadata.shares.into()))
+			.collect();
+		assert_eq!(owner_shares, vec![(1, 50), (2, 20), (3, 30)]);
+	})
+}
+
+#[test]
+fn claim_onwership_failure_exactly_half_shares() {
+	new_test_ext().execute_with(|| {
+		let share_price = 1;
+
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			share_price,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(data);
+
+		let amount = TotalSupply::get() / 2;
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			amount,
+			2
+		));
+
+		assert_noop!(
+			ProportionalAssetModule::claim_onwership(Origin::signed(2), id),
+			Error::<Test>::NotEnoughShares
+		);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			1,
+			2
+		));
+
+		assert_ok!(ProportionalAssetModule::claim_onwership(Origin::signed(2), id));
+		assert!(ProportionalAssetModule::is_owner_of(&2, &id));
+	})
+}
+
+#[test]
+fn burn_shares_success() {
+	new_test_ext().execute_with(|| {
+		let share_price = 10;
+
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			share_price,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::burn_shares(Origin::signed(1), id, 10));
+
+		let expected_event = Event::ProportionalAssetModule(crate::Event::SharesBurned(id, 1, 10));
+		assert_eq!(System::events()[1].event, expected_event);
+
+		assert_ok!(ProportionalAssetModule::burn_shares(Origin::signed(1), id, 5));
+
+		assert_eq!(ProportionalAssetToBurnedShares::<Test>::get(id), 15);
+		assert_eq!(ProportionalAssetModule::effective_supply(&id), TotalSupply::get() - 15);
+
+		let stored_metadata = ProportionalAssetToOwnerToMetadata::<Test>::get(id, &1).unwrap();
+		assert_eq!(stored_metadata.shares, Shares(TotalSupply::get() - 15));
+	})
+}
+
+#[test]
+fn burn_shares_failure_not_enough_shares() {
+	new_test_ext().execute_with(|| {
+		let share_price = 10;
+
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			share_price,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(data);
+
+		assert_noop!(
+			ProportionalAssetModule::burn_shares(Origin::signed(1), id, TotalSupply::get() + 1),
+			Error::<Test>::NotEnoughShares
+		);
+	})
+}
+
+#[test]
+fn claim_onwership_respects_burned_shares() {
+	new_test_ext().execute_with(|| {
+		let share_price = 1;
+
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			share_price,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			45,
+			2
+		));
+
+		assert_noop!(
+			ProportionalAssetModule::claim_onwership(Origin::signed(2), id),
+			Error::<Test>::NotEnoughShares
+		);
+
+		// 45 out of the remaining 80 shares is a majority
+		assert_ok!(ProportionalAssetModule::burn_shares(Origin::signed(1), id, 20));
+
+		assert_ok!(ProportionalAssetModule::claim_onwership(Origin::signed(2), id));
+		assert!(ProportionalAssetModule::is_owner_of(&2, &id));
+	})
+}
+
+#[test]
+fn ownership_percentage_success() {
+	new_test_ext().execute_with(|| {
+		let share_price = 10;
+
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			share_price,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			25,
+			2
+		));
+
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &2), 25);
+		assert_eq!(
+			ProportionalAssetModule::ownership_percentage(&id, &2),
+			Permill::from_percent(25)
+		);
+	})
+}
+
+#[test]
+fn ownership_percentage_unknown_account() {
+	new_test_ext().execute_with(|| {
+		let share_price = 10;
+
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			share_price,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(data);
+
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &3), 0);
+		assert_eq!(ProportionalAssetModule::ownership_percentage(&id, &3), Permill::zero());
+	})
+}
+
+#[test]
+fn transfer_shares_to_account_removes_empty_metadata() {
+	new_test_ext().execute_with(|| {
+		let share_price = 10;
+
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			share_price,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			30,
+			2
+		));
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(2),
+			id,
+			30,
+			3
+		));
+
+		assert!(!ProportionalAssetToOwnerToMetadata::<Test>::contains_key(id, &2));
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &3), 30);
+	})
+}
+
+#[test]
+fn buy_shares_removes_empty_metadata() {
+	new_test_ext().execute_with(|| {
+		let share_price = 1;
+
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			share_price,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(data);
+
+		let offers = TotalSupply::get();
+
+		assert_ok!(ProportionalAssetModule::offer_shares(
+			Origin::signed(1),
+			id,
+			offers,
+			share_price,
+			None
+		));
+
+		assert_ok!(ProportionalAssetModule::buy_shares(
+			Origin::signed(3),
+			id,
+			offers,
+			Balance::from(offers) * share_price,
+			1,
+			Balance::MAX
+		));
+
+		assert!(!ProportionalAssetToOwnerToMetadata::<Test>::contains_key(id, &1));
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &3), TotalSupply::get());
+	})
+}
+
+#[test]
+fn transfer_shares_to_account_vacates_main_owner() {
+	new_test_ext().execute_with(|| {
+		let share_price = 10;
+
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			share_price,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			60,
+			2
+		));
+
+		let expected_event = Event::ProportionalAssetModule(crate::Event::MainOwnerVacated(id));
+		assert_eq!(System::events()[2].event, expected_event);
+
+		assert_eq!(ProportionalAssetModule::get_main_owner_by_asset(&id), None);
+	})
+}
+
+#[test]
+fn genesis_config_preloads_assets() {
+	let data = get_test_data();
+
+	let owners = vec![(1, 40, 10), (2, 60, 20)];
+
+	new_test_ext_with_assets(vec![(data.clone(), owners)]).execute_with(|| {
+		let id = get_hash_from_vec(data);
+
+		let stored_metadata_1 = ProportionalAssetToOwnerToMetadata::<Test>::get(id, &1).unwrap();
+		assert_eq!(stored_metadata_1.shares, Shares(40));
+		assert_eq!(stored_metadata_1.price, 10);
+
+		let stored_metadata_2 = ProportionalAssetToOwnerToMetadata::<Test>::get(id, &2).unwrap();
+		assert_eq!(stored_metadata_2.shares, Shares(60));
+		assert_eq!(stored_metadata_2.price, 20);
+
+		assert_eq!(ProportionalAssetModule::get_main_owner_by_asset(&id), Some(2));
+	});
+}
+
+#[test]
+#[should_panic(expected = "Shares of a genesis asset must sum up to the total supply")]
+fn genesis_config_failure_invalid_shares() {
+	new_test_ext_with_assets(vec![(get_test_data(), vec![(1, 40, 10), (2, 40, 20)])]);
+}
+
+#[test]
+fn destroy_asset_success() {
+	new_test_ext().execute_with(|| {
+		let share_price = 10;
+
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			share_price,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::destroy_asset(Origin::signed(1), id));
+
+		let expected_emptied_event = Event::ProportionalAssetModule(crate::Event::AssetEmptied(id));
+		assert_eq!(System::events()[1].event, expected_emptied_event);
+
+		let expected_event = Event::ProportionalAssetModule(crate::Event::AssetDestroyed(id));
+		assert_eq!(System::events()[2].event, expected_event);
+
+		assert!(!ProportionalAssetToOwnerToMetadata::<Test>::contains_key(id, &1));
+		assert!(!ProportionalAssetToMainOwner::<Test>::contains_key(id));
+	})
+}
+
+#[test]
+fn destroy_asset_failure_not_main_owner() {
+	new_test_ext().execute_with(|| {
+		let share_price = 10;
+
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			share_price,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(data);
+
+		assert_noop!(
+			ProportionalAssetModule::destroy_asset(Origin::signed(2), id),
+			Error::<Test>::NotMainOwner
+		);
+	})
+}
+
+#[test]
+fn destroy_asset_failure_multiple_owners() {
+	new_test_ext().execute_with(|| {
+		let share_price = 10;
+
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			share_price,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			10,
+			2
+		));
+
+		assert_noop!(
+			ProportionalAssetModule::destroy_asset(Origin::signed(1), id),
+			Error::<Test>::AssetHasMultipleOwners
+		);
+	})
+}
+
+#[test]
+fn create_proportional_asset_reserves_deposit() {
+	new_test_ext().execute_with(|| {
+		AssetDeposit::set(10);
+
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			10,
+			None,
+			None
+		));
+
+		assert_eq!(Balances::reserved_balance(1), 10);
+
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::destroy_asset(Origin::signed(1), id));
+
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), get_initial_balances()[0].1);
+	})
+}
+
+#[test]
+fn create_proportional_asset_failure_insufficient_deposit() {
+	new_test_ext().execute_with(|| {
+		AssetDeposit::set(get_initial_balances()[0].1 + 1);
+
+		assert_noop!(
+			ProportionalAssetModule::create_proportional_asset(
+				Origin::signed(1),
+				get_test_data(),
+				10,
+				None,
+				None
+			),
+			Error::<Test>::InsufficientBalance
+		);
+	})
+}
+
+#[test]
+fn buy_shares_before_offer_expiry() {
+	new_test_ext().execute_with(|| {
+		let share_price = 1;
+
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			share_price,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(
+			Origin::signed(1),
+			id,
+			10,
+			share_price,
+			Some(5)
+		));
+
+		System::set_block_number(5);
+
+		assert_ok!(ProportionalAssetModule::buy_shares(
+			Origin::signed(2),
+			id,
+			10,
+			10,
+			1,
+			Balance::MAX
+		));
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &2), 10);
+	})
+}
+
+#[test]
+fn buy_shares_failure_offer_expired() {
+	new_test_ext().execute_with(|| {
+		let share_price = 1;
+
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			share_price,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(
+			Origin::signed(1),
+			id,
+			10,
+			share_price,
+			Some(5)
+		));
+
+		System::set_block_number(6);
+
+		assert_noop!(
+			ProportionalAssetModule::buy_shares(Origin::signed(2), id, 10, 10, 1, Balance::MAX),
+			Error::<Test>::OfferExpired
+		);
+	})
+}
+
+#[test]
+fn buy_shares_success_allowlisted_buyer() {
+	new_test_ext().execute_with(|| {
+		let share_price = 1;
+
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			share_price,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(
+			Origin::signed(1),
+			id,
+			10,
+			share_price,
+			None
+		));
+
+		assert_ok!(ProportionalAssetModule::set_offer_allowlist(Origin::signed(1), id, vec![2]));
+
+		assert_ok!(ProportionalAssetModule::buy_shares(
+			Origin::signed(2),
+			id,
+			10,
+			10,
+			1,
+			Balance::MAX
+		));
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &2), 10);
+	})
+}
+
+#[test]
+fn buy_shares_failure_buyer_not_allowed() {
+	new_test_ext().execute_with(|| {
+		let share_price = 1;
+
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			share_price,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(
+			Origin::signed(1),
+			id,
+			10,
+			share_price,
+			None
+		));
+
+		assert_ok!(ProportionalAssetModule::set_offer_allowlist(Origin::signed(1), id, vec![2]));
+
+		assert_noop!(
+			ProportionalAssetModule::buy_shares(Origin::signed(3), id, 10, 10, 1, Balance::MAX),
+			Error::<Test>::BuyerNotAllowed
+		);
+	})
+}
+
+#[test]
+fn buy_shares_failure_incorrect_seller_not_owner() {
+	new_test_ext().execute_with(|| {
+		let share_price = 1;
+
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			share_price,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(
+			Origin::signed(1),
+			id,
+			10,
+			share_price,
+			None
+		));
+
+		assert_noop!(
+			ProportionalAssetModule::buy_shares(Origin::signed(2), id, 5, 5, 3, Balance::MAX),
+			Error::<Test>::IncorrectSeller
+		);
+	})
+}
+
+#[test]
+fn buy_shares_success_minority_seller() {
+	new_test_ext().execute_with(|| {
+		let share_price = 1;
+
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			share_price,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			30,
+			2
+		));
+
+		assert_ok!(ProportionalAssetModule::offer_shares(
+			Origin::signed(2),
+			id,
+			10,
+			share_price,
+			None
+		));
+
+		assert_ok!(ProportionalAssetModule::buy_shares(
+			Origin::signed(3),
+			id,
+			5,
+			5,
+			2,
+			Balance::MAX
+		));
+
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &2), 25);
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &3), 5);
+	})
+}
+
+#[test]
+fn buy_shares_success_from_minority_stakeholder() {
+	new_test_ext().execute_with(|| {
+		let share_price = 1;
+
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			share_price,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			40,
+			2
+		));
+
+		assert_ok!(ProportionalAssetModule::offer_shares(
+			Origin::signed(2),
+			id,
+			10,
+			share_price,
+			None
+		));
+
+		assert_ok!(ProportionalAssetModule::buy_shares(
+			Origin::signed(3),
+			id,
+			5,
+			5,
+			2,
+			Balance::MAX
+		));
+
+		let stored_metadata_2 = ProportionalAssetToOwnerToMetadata::<Test>::get(id, &2).unwrap();
+		assert_eq!(stored_metadata_2.shares, Shares(35));
+		assert_eq!(stored_metadata_2.offers, Shares(5));
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &3), 5);
+	})
+}
+
+#[test]
+fn buy_shares_failure_seller_without_offers() {
+	new_test_ext().execute_with(|| {
+		let share_price = 1;
+
+		let data = get_test_data();
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			share_price,
+			None,
+			None
+		));
+
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			40,
+			2
+		));
+
+		assert_noop!(
+			ProportionalAssetModule::buy_shares(Origin::signed(3), id, 5, 5, 2, Balance::MAX),
+			Error::<Test>::IncorrectSeller
+		);
+	})
+}
+
+#[test]
+fn transfer_shares_batch_success() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_batch(
+			Origin::signed(1),
+			id,
+			vec![(2, 10), (3, 20), (2, 5)]
+		));
+
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &1), TotalSupply::get() - 35);
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &2), 15);
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &3), 20);
+	});
+}
+
+#[test]
+fn transfer_shares_batch_failure_exceeds_shares() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_noop!(
+			ProportionalAssetModule::transfer_shares_batch(
+				Origin::signed(1),
+				id,
+				vec![(2, 60), (3, TotalSupply::get() - 59)]
+			),
+			Error::<Test>::IncorrectSharesSelection
+		);
+	});
+}
+
+#[test]
+fn transfer_shares_batch_failure_too_large() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_noop!(
+			ProportionalAssetModule::transfer_shares_batch(
+				Origin::signed(1),
+				id,
+				(2..8).map(|to| (to, 1)).collect()
+			),
+			Error::<Test>::BatchTooLarge
+		);
+	});
+}
+
+#[test]
+fn offer_shares_at_price_floor_success() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::set_price_floor(Origin::signed(1), id, 5));
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 10, 5, None));
+
+		let metadata = ProportionalAssetToOwnerToMetadata::<Test>::get(id, 1).unwrap();
+		assert_eq!(metadata.price, 5);
+	});
+}
+
+#[test]
+fn offer_shares_below_price_floor_failure() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::set_price_floor(Origin::signed(1), id, 5));
+		assert_noop!(
+			ProportionalAssetModule::offer_shares(Origin::signed(1), id, 10, 4, None),
+			Error::<Test>::PriceBelowFloor
+		);
+
+		// Removing the floor allows any price again
+		assert_ok!(ProportionalAssetModule::set_price_floor(Origin::signed(1), id, 0));
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 10, 4, None));
+	});
+}
+
+#[test]
+fn set_price_floor_failure_not_main_owner() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_noop!(
+			ProportionalAssetModule::set_price_floor(Origin::signed(2), id, 5),
+			Error::<Test>::NotMainOwner
+		);
+	});
+}
+
+#[test]
+fn offer_shares_replaces_previous_offer() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 30, 1, None));
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 10, 1, None));
+
+		let metadata = ProportionalAssetToOwnerToMetadata::<Test>::get(id, 1).unwrap();
+		assert_eq!(metadata.offers, Shares(10));
+	});
+}
+
+#[test]
+fn increase_offer_accumulates() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			2,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 10, 2, None));
+		assert_ok!(ProportionalAssetModule::increase_offer(Origin::signed(1), id, 15));
+
+		let metadata = ProportionalAssetToOwnerToMetadata::<Test>::get(id, 1).unwrap();
+		assert_eq!(metadata.offers, Shares(25));
+		assert_eq!(metadata.price, 2);
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::SharesOffered {
+			id,
+			seller: 1,
+			shares: 25,
+			price: 2,
+		}));
+	});
+}
+
+#[test]
+fn increase_offer_clamps_to_held_shares() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 90, 1, None));
+		assert_ok!(ProportionalAssetModule::increase_offer(Origin::signed(1), id, 20));
+
+		let metadata = ProportionalAssetToOwnerToMetadata::<Test>::get(id, 1).unwrap();
+		assert_eq!(metadata.offers, Shares(TotalSupply::get()));
+	});
+}
+
+#[test]
+fn increase_offer_failure_not_an_owner() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_noop!(
+			ProportionalAssetModule::increase_offer(Origin::signed(2), id, 5),
+			Error::<Test>::NotAnOwner
+		);
+	});
+}
+
+#[test]
+fn distribute_dividend_proportional_to_shares() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		// 60/40 split between account 1 and account 2
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			40,
+			2
+		));
+
+		assert_ok!(ProportionalAssetModule::distribute_dividend(Origin::signed(3), id, 200));
+
+		let initial_balances = get_initial_balances();
+		assert_eq!(Balances::free_balance(1), initial_balances[0].1 + 120);
+		assert_eq!(Balances::free_balance(2), initial_balances[1].1 + 80);
+		assert_eq!(Balances::free_balance(3), initial_balances[2].1 - 200);
+
+		System::assert_last_event(Event::ProportionalAssetModule(
+			crate::Event::DividendDistributed(id, 200),
+		));
+	});
+}
+
+#[test]
+fn distribute_dividend_dust_goes_to_largest_remainder() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			40,
+			2
+		));
+
+		// 11 * 60 / 100 = 6.6 and 11 * 40 / 100 = 4.4 leave 1 for the largest remainder
+		assert_ok!(ProportionalAssetModule::distribute_dividend(Origin::signed(3), id, 11));
+
+		let initial_balances = get_initial_balances();
+		assert_eq!(Balances::free_balance(1), initial_balances[0].1 + 7);
+		assert_eq!(Balances::free_balance(2), initial_balances[1].1 + 4);
+	});
+}
+
+/// A consumer of the pallet that weights votes by the ownership of an asset.
+fn voting_weight<Inspect: ProportionalAssetInspect<u64, H256>>(id: &H256, who: &u64) -> u64 {
+	match Inspect::main_owner(id) {
+		// The main owner has a double vote
+		Some(main_owner) if &main_owner == who => Inspect::shares_of(id, who) * 2,
+		_ if Inspect::is_owner(id, who) => Inspect::shares_of(id, who),
+		_ => 0,
+	}
+}
+
+#[test]
+fn inspect_ownership_through_trait() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			40,
+			2
+		));
+
+		assert_eq!(
+			<ProportionalAssetModule as ProportionalAssetInspect<_, _>>::total_owners(&id),
+			2
+		);
+		assert_eq!(voting_weight::<ProportionalAssetModule>(&id, &1), 120);
+		assert_eq!(voting_weight::<ProportionalAssetModule>(&id, &2), 40);
+		assert_eq!(voting_weight::<ProportionalAssetModule>(&id, &3), 0);
+	});
+}
+
+#[test]
+fn owner_count_tracks_owners() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+		assert_eq!(ProportionalAssetModule::owner_count(&id), 1);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			10,
+			2
+		));
+		assert_eq!(ProportionalAssetModule::owner_count(&id), 2);
+
+		// Transferring to an existing owner does not change the count
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			10,
+			2
+		));
+		assert_eq!(ProportionalAssetModule::owner_count(&id), 2);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(2), id, 20, 1, None));
+		assert_ok!(ProportionalAssetModule::buy_shares(
+			Origin::signed(3),
+			id,
+			5,
+			5,
+			2,
+			Balance::MAX
+		));
+		assert_eq!(ProportionalAssetModule::owner_count(&id), 3);
+
+		// Selling off all the shares removes the owner
+		assert_ok!(ProportionalAssetModule::buy_shares(
+			Origin::signed(3),
+			id,
+			15,
+			15,
+			2,
+			Balance::MAX
+		));
+		assert_eq!(ProportionalAssetModule::owner_count(&id), 2);
+	});
+}
+
+#[test]
+fn transfer_main_ownership_success() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			60,
+			2
+		));
+
+		// A main owner record that was not vacated after the majority moved
+		ProportionalAssetToMainOwner::<Test>::insert(id, 1);
+
+		assert_ok!(ProportionalAssetModule::transfer_main_ownership(Origin::signed(1), id, 2));
+
+		assert_eq!(ProportionalAssetToMainOwner::<Test>::get(id), Some(2));
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::MainOwnerSet(
+			2, id,
+		)));
+	});
+}
+
+#[test]
+fn transfer_main_ownership_moves_the_deposit() {
+	new_test_ext().execute_with(|| {
+		AssetDeposit::set(10);
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			60,
+			4
+		));
+		ProportionalAssetToMainOwner::<Test>::insert(id, 1);
+
+		// The recipient cannot reserve the deposit
+		assert_noop!(
+			ProportionalAssetModule::transfer_main_ownership(Origin::signed(1), id, 4),
+			Error::<Test>::InsufficientBalance
+		);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(4),
+			id,
+			60,
+			3
+		));
+		ProportionalAssetToMainOwner::<Test>::insert(id, 1);
+
+		assert_ok!(ProportionalAssetModule::transfer_main_ownership(Origin::signed(1), id, 3));
+
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 50);
+		assert_eq!(Balances::reserved_balance(3), 10);
+		assert_eq!(ProportionalAssetToDeposit::<Test>::get(id), Some((3, 10)));
+	});
+}
+
+#[test]
+fn transfer_main_ownership_failure_without_majority() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			40,
+			2
+		));
+
+		assert_noop!(
+			ProportionalAssetModule::transfer_main_ownership(Origin::signed(1), id, 2),
+			Error::<Test>::NotEnoughShares
+		);
+		assert_noop!(
+			ProportionalAssetModule::transfer_main_ownership(Origin::signed(2), id, 1),
+			Error::<Test>::NotMainOwner
+		);
+	});
+}
+
+#[test]
+fn zero_amounts_are_rejected() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_noop!(
+			ProportionalAssetModule::offer_shares(Origin::signed(1), id, 0, 1, None),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			ProportionalAssetModule::increase_offer(Origin::signed(1), id, 0),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			ProportionalAssetModule::transfer_shares_to_account(Origin::signed(1), id, 0, 2),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			ProportionalAssetModule::transfer_shares_batch(
+				Origin::signed(1),
+				id,
+				vec![(2, 10), (3, 0)]
+			),
+			Error::<Test>::ZeroAmount
+		);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 10, 1, None));
+		assert_noop!(
+			ProportionalAssetModule::buy_shares(Origin::signed(2), id, 0, 0, 1, Balance::MAX),
+			Error::<Test>::ZeroAmount
+		);
+	});
+}
+
+#[test]
+fn frozen_asset_blocks_trading() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 10, 1, None));
+		assert_ok!(ProportionalAssetModule::set_frozen(Origin::signed(1), id, true));
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::AssetFrozen(id)));
+
+		assert_noop!(
+			ProportionalAssetModule::offer_shares(Origin::signed(1), id, 20, 1, None),
+			Error::<Test>::AssetFrozen
+		);
+		assert_noop!(
+			ProportionalAssetModule::transfer_shares_to_account(Origin::signed(1), id, 10, 2),
+			Error::<Test>::AssetFrozen
+		);
+		assert_noop!(
+			ProportionalAssetModule::buy_shares(Origin::signed(2), id, 10, 10, 1, Balance::MAX),
+			free_rejection(Error::<Test>::AssetFrozen)
+		);
+	});
+}
+
+#[test]
+fn unfrozen_asset_allows_trading() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::set_frozen(Origin::signed(1), id, true));
+		assert_ok!(ProportionalAssetModule::set_frozen(Origin::signed(1), id, false));
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::AssetUnfrozen(id)));
+
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 20, 1, None));
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			10,
+			2
+		));
+		assert_ok!(ProportionalAssetModule::buy_shares(
+			Origin::signed(2),
+			id,
+			10,
+			10,
+			1,
+			Balance::MAX
+		));
+	});
+}
+
+#[test]
+fn set_frozen_failure_not_main_owner() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_noop!(
+			ProportionalAssetModule::set_frozen(Origin::signed(2), id, true),
+			Error::<Test>::NotMainOwner
+		);
+	});
+}
+
+#[test]
+fn create_named_asset() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			Some(b"House".to_vec()),
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_eq!(ProportionalAssetModule::name_of(&id), Some(b"House".to_vec()));
+	});
+}
+
+#[test]
+fn create_named_asset_failure_name_too_long() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ProportionalAssetModule::create_proportional_asset(
+				Origin::signed(1),
+				vec![1, 2, 3],
+				1,
+				Some(vec![1; 17]),
+				None
+			),
+			Error::<Test>::NameTooLong
+		);
+	});
+}
+
+#[test]
+fn buy_shares_emits_trade_settled() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			2,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 20, 2, None));
+		assert_ok!(ProportionalAssetModule::buy_shares(
+			Origin::signed(2),
+			id,
+			10,
+			20,
+			1,
+			Balance::MAX
+		));
+
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::TradeSettled {
+			id,
+			buyer: 2,
+			seller: 1,
+			shares: 10,
+			price: 2,
+			buyer_shares_after: ProportionalAssetModule::shares_of(&id, &2),
+			seller_shares_after: ProportionalAssetModule::shares_of(&id, &1),
+		}));
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &2), 10);
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &1), 90);
+	});
+}
+
+#[test]
+fn offer_shares_failure_zero_price() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			0,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 10, 3, None));
+		assert_noop!(
+			ProportionalAssetModule::offer_shares(Origin::signed(1), id, 20, 0, None),
+			Error::<Test>::ZeroPrice
+		);
+
+		// The prior offer is still live
+		assert_ok!(ProportionalAssetModule::buy_shares(
+			Origin::signed(2),
+			id,
+			10,
+			30,
+			1,
+			Balance::MAX
+		));
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &2), 10);
+	});
+}
+
+#[test]
+fn increase_offer_failure_zero_price() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			0,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_noop!(
+			ProportionalAssetModule::increase_offer(Origin::signed(1), id, 10),
+			Error::<Test>::ZeroPrice
+		);
+	});
+}
+
+#[test]
+fn total_accounted_shares_equals_supply() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+		assert_eq!(ProportionalAssetModule::total_accounted_shares(&id), 100);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			30,
+			2
+		));
+		assert_eq!(ProportionalAssetModule::total_accounted_shares(&id), 100);
+	});
+}
+
+#[test]
+fn fill_cheaper_order() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::place_order(Origin::signed(1), id, 10, 3));
+		assert_ok!(ProportionalAssetModule::place_order(Origin::signed(1), id, 10, 2));
+		assert_eq!(ProportionalAssetModule::ordered_shares(&id, &1), 20);
+
+		// The second order is the cheaper one
+		assert_ok!(ProportionalAssetModule::fill_order(Origin::signed(2), id, 1, 10));
+
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &2), 10);
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &1), 90);
+		assert_eq!(Balances::free_balance(2), get_initial_balances()[1].1 - 20);
+		assert_eq!(Balances::free_balance(1), get_initial_balances()[0].1 + 20);
+
+		assert!(crate::Orders::<Test>::get(id, 1).is_none());
+		assert_eq!(crate::Orders::<Test>::get(id, 0).map(|order| order.price), Some(3));
+		assert_eq!(ProportionalAssetModule::ordered_shares(&id, &1), 10);
+	});
+}
+
+#[test]
+fn fill_order_partially() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::place_order(Origin::signed(1), id, 10, 2));
+		assert_ok!(ProportionalAssetModule::fill_order(Origin::signed(2), id, 0, 4));
+
+		assert_eq!(crate::Orders::<Test>::get(id, 0).map(|order| order.shares), Some(6));
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &2), 4);
+	});
+}
+
+#[test]
+fn place_order_failure_over_committed() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 60, 1, None));
+		assert_noop!(
+			ProportionalAssetModule::place_order(Origin::signed(1), id, 50, 2),
+			Error::<Test>::OverCommittedOffers
+		);
+	});
+}
+
+#[test]
+fn cancel_order_success() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::place_order(Origin::signed(1), id, 10, 2));
+		assert_noop!(
+			ProportionalAssetModule::cancel_order(Origin::signed(2), id, 0),
+			Error::<Test>::NotOrderSeller
+		);
+
+		assert_ok!(ProportionalAssetModule::cancel_order(Origin::signed(1), id, 0));
+		assert!(crate::Orders::<Test>::get(id, 0).is_none());
+		assert_eq!(ProportionalAssetModule::ordered_shares(&id, &1), 0);
+		assert_noop!(
+			ProportionalAssetModule::fill_order(Origin::signed(2), id, 0, 1),
+			Error::<Test>::OrderNotFound
+		);
+	});
+}
+
+#[test]
+fn buy_shares_refunds_overpayment() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			2,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 10, 2, None));
+
+		// 5 shares at 2 owe 10, the buyer sends 40
+		assert_ok!(ProportionalAssetModule::buy_shares(
+			Origin::signed(2),
+			id,
+			5,
+			40,
+			1,
+			Balance::MAX
+		));
+
+		let initial_balances = get_initial_balances();
+		assert_eq!(Balances::free_balance(1), initial_balances[0].1 + 10);
+		assert_eq!(Balances::free_balance(2), initial_balances[1].1 - 10);
+	});
+}
+
+#[test]
+fn migrate_to_v1() {
+	use crate::migrations::v1::{MigrateToV1, ProportionalAssetToOwnerToMetadata};
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<ProportionalAssetModule>();
+
+		let id = get_hash_from_vec(vec![1, 2, 3]);
+
+		// The old layout has the same fields, but allowed empty entries and stale offers
+		ProportionalAssetToOwnerToMetadata::<Test>::insert(
+			id,
+			1,
+			crate::MetaData { offers: Shares(90), shares: Shares(60), price: 2 },
+		);
+		ProportionalAssetToOwnerToMetadata::<Test>::insert(
+			id,
+			2,
+			crate::MetaData { offers: Shares(0), shares: Shares(40), price: 1 },
+		);
+		ProportionalAssetToOwnerToMetadata::<Test>::insert(
+			id,
+			3,
+			crate::MetaData { offers: Shares(0), shares: Shares(0), price: 0 },
+		);
+
+		// Legacy main owners without a majority, one of them without shares at all
+		let other_id = get_hash_from_vec(vec![4, 5, 6]);
+		ProportionalAssetToOwnerToMetadata::<Test>::insert(
+			other_id,
+			2,
+			crate::MetaData { offers: Shares(0), shares: Shares(50), price: 1 },
+		);
+		ProportionalAssetToMainOwner::<Test>::insert(id, 3);
+		ProportionalAssetToMainOwner::<Test>::insert(other_id, 2);
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(ProportionalAssetModule::on_chain_storage_version(), 1);
+		assert_eq!(
+			ProportionalAssetToOwnerToMetadata::<Test>::get(id, 1),
+			Some(crate::MetaData { offers: Shares(60), shares: Shares(60), price: 2 })
+		);
+		assert_eq!(
+			ProportionalAssetToOwnerToMetadata::<Test>::get(id, 2),
+			Some(crate::MetaData { offers: Shares(0), shares: Shares(40), price: 1 })
+		);
+		assert_eq!(ProportionalAssetToOwnerToMetadata::<Test>::get(id, 3), None);
+		assert_eq!(ProportionalAssetModule::owner_count(&id), 2);
+		assert_eq!(ProportionalAssetToMainOwner::<Test>::get(id), None);
+		assert_eq!(ProportionalAssetToMainOwner::<Test>::get(other_id), None);
+	});
+}
+
+#[test]
+fn migrate_to_v2() {
+	use crate::migrations::{v1, v2::MigrateToV2};
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<ProportionalAssetModule>();
+
+		let id = get_hash_from_vec(vec![1, 2, 3]);
+
+		v1::ProportionalAssetToOwnerToMetadata::<Test>::insert(
+			id,
+			1,
+			crate::MetaData { offers: Shares(10), shares: Shares(60), price: u64::MAX },
+		);
+
+		MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(ProportionalAssetModule::on_chain_storage_version(), 2);
+		assert_eq!(
+			ProportionalAssetToOwnerToMetadata::<Test>::get(id, 1),
+			Some(crate::MetaData {
+				offers: Shares(10),
+				shares: Shares(60),
+				price: u64::MAX as u128
+			})
+		);
+
+		// A second run is a no-op
+		MigrateToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(
+			ProportionalAssetToOwnerToMetadata::<Test>::get(id, 1).unwrap().price,
+			u64::MAX as u128
+		);
+	});
+}
+
+#[test]
+fn migrate_to_v3() {
+	use crate::{
+		migrations::v3::MigrateToV3, NextOrderId, Order, OrderedShares, Orders,
+		ProportionalAssetToOfferAllowlist, ProportionalAssetToOfferExpiry,
+	};
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+	use frame_support::{traits::ConstU32, BoundedVec};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(2).put::<ProportionalAssetModule>();
+		System::set_block_number(10);
+		NextOrderId::<Test>::put(5);
+
+		let id = get_hash_from_vec(vec![1, 2, 3]);
+		ProportionalAssetToOwnerToMetadata::<Test>::insert(
+			id,
+			1,
+			crate::MetaData { offers: Shares(10), shares: Shares(60), price: 3 },
+		);
+		ProportionalAssetToOwnerToMetadata::<Test>::insert(
+			id,
+			2,
+			crate::MetaData { offers: Shares(0), shares: Shares(30), price: 2 },
+		);
+		// An expired offer is dropped
+		ProportionalAssetToOwnerToMetadata::<Test>::insert(
+			id,
+			3,
+			crate::MetaData { offers: Shares(5), shares: Shares(10), price: 4 },
+		);
+		ProportionalAssetToOfferExpiry::<Test>::insert(id, 3, 9);
+		// Restricted offers stay offers
+		ProportionalAssetToOwnerToMetadata::<Test>::insert(
+			id,
+			4,
+			crate::MetaData { offers: Shares(2), shares: Shares(5), price: 5 },
+		);
+		let allowlist: BoundedVec<u64, ConstU32<10>> = vec![2].try_into().unwrap();
+		ProportionalAssetToOfferAllowlist::<Test>::insert(id, 4, allowlist);
+		ProportionalAssetToOwnerToMetadata::<Test>::insert(
+			id,
+			5,
+			crate::MetaData { offers: Shares(3), shares: Shares(5), price: 6 },
+		);
+		ProportionalAssetToOfferExpiry::<Test>::insert(id, 5, 10);
+
+		MigrateToV3::<Test>::on_runtime_upgrade();
+
+		assert_eq!(ProportionalAssetModule::on_chain_storage_version(), 3);
+		assert_eq!(
+			Orders::<Test>::iter_prefix(id).collect::<Vec<_>>(),
+			vec![(5, Order { seller: 1, shares: 10, price: 3 })]
+		);
+		assert_eq!(NextOrderId::<Test>::get(), 6);
+		assert_eq!(OrderedShares::<Test>::get(id, 1), 10);
+		assert_eq!(OrderedShares::<Test>::get(id, 3), 0);
+		assert_eq!(ProportionalAssetToOfferExpiry::<Test>::get(id, 3), None);
+		for who in [1, 2, 3] {
+			assert!(ProportionalAssetToOwnerToMetadata::<Test>::get(id, who)
+				.unwrap()
+				.offers
+				.is_zero());
+		}
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &1), 60);
+		assert_eq!(ProportionalAssetModule::active_offer(&id, &1), Some((10, 3)));
+		assert_eq!(ProportionalAssetModule::active_offer(&id, &4), Some((2, 5)));
+		assert_eq!(ProportionalAssetModule::active_offer(&id, &5), Some((3, 6)));
+		assert_eq!(ProportionalAssetToOfferExpiry::<Test>::get(id, 5), Some(10));
+		assert_eq!(OrderedShares::<Test>::get(id, 4), 0);
+		assert_eq!(ProportionalAssetModule::market_depth(&id), (15, Some(3)));
+
+		// A second run is a no-op
+		MigrateToV3::<Test>::on_runtime_upgrade();
+		assert_eq!(Orders::<Test>::iter_prefix(id).count(), 1);
+	});
+}
+
+#[test]
+fn migrate_to_v4() {
+	use crate::{migrations::v4::MigrateToV4, ProportionalAssetToOwnerReverseIndex};
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			40,
+			2
+		));
+
+		// The indexes did not exist when the asset was created
+		StorageVersion::new(3).put::<ProportionalAssetModule>();
+		let _ = ProportionalAssetToOwnerReverseIndex::<Test>::clear(u32::MAX, None);
+		let _ = MainOwnerToAssets::<Test>::clear(u32::MAX, None);
+		Proposals::<Test>::insert(id, 0, H256::repeat_byte(1));
+
+		// A legacy main owner left with a minority
+		let minority_data: Vec<u8> = vec![4, 5, 6];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(2),
+			minority_data.clone(),
+			1,
+			None,
+			None
+		));
+		let minority_id = get_hash_from_vec(minority_data);
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(2),
+			minority_id,
+			60,
+			1
+		));
+		ProportionalAssetToMainOwner::<Test>::insert(minority_id, 2);
+
+		MigrateToV4::<Test>::on_runtime_upgrade();
+
+		assert_eq!(ProportionalAssetModule::on_chain_storage_version(), 4);
+		let mut held = vec![id, minority_id];
+		held.sort();
+		for who in [1, 2] {
+			let mut assets = ProportionalAssetModule::assets_of(&who);
+			assets.sort();
+			assert_eq!(assets, held);
+		}
+		assert_eq!(ProportionalAssetModule::main_owned_assets(&1), vec![id]);
+		assert_eq!(ProportionalAssetModule::main_owned_assets(&2), vec![]);
+		assert_eq!(ProportionalAssetToMainOwner::<Test>::get(minority_id), None);
+		assert_eq!(OpenProposals::<Test>::get(id), 1);
+		assert_ok!(ProportionalAssetModule::do_try_state());
+	});
+}
+
+#[test]
+fn fungibles_traits_over_shares() {
+	use frame_support::traits::tokens::fungibles::{Inspect, Mutate, Transfer};
+
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_eq!(<ProportionalAssetModule as Inspect<u64>>::total_issuance(id), 100);
+		assert_eq!(<ProportionalAssetModule as Inspect<u64>>::balance(id, &1), 100);
+
+		assert_eq!(
+			<ProportionalAssetModule as Transfer<u64>>::transfer(id, &1, &2, 30, false),
+			Ok(30)
+		);
+		assert_eq!(<ProportionalAssetModule as Inspect<u64>>::balance(id, &1), 70);
+		assert_eq!(<ProportionalAssetModule as Inspect<u64>>::balance(id, &2), 30);
+
+		assert_eq!(<ProportionalAssetModule as Mutate<u64>>::burn_from(id, &2, 10), Ok(10));
+		assert_eq!(<ProportionalAssetModule as Inspect<u64>>::total_issuance(id), 90);
+
+		assert_noop!(
+			<ProportionalAssetModule as Mutate<u64>>::mint_into(id, &2, 10),
+			Error::<Test>::TotalSupplyExceeded
+		);
+	});
+}
+
+#[test]
+fn transfer_shares_failure_too_many_holders() {
+	new_test_ext().execute_with(|| {
+		MaxHolders::set(2);
+
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			10,
+			2
+		));
+		assert_noop!(
+			ProportionalAssetModule::transfer_shares_to_account(Origin::signed(1), id, 10, 3),
+			Error::<Test>::TooManyHolders
+		);
+
+		// A holder selling off all of the shares makes room for the buyer
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(2), id, 10, 1, None));
+		assert_ok!(ProportionalAssetModule::buy_shares(
+			Origin::signed(3),
+			id,
+			10,
+			10,
+			2,
+			Balance::MAX
+		));
+		assert_eq!(ProportionalAssetModule::owner_count(&id), 2);
+	});
+}
+
+#[test]
+fn transfer_shares_failure_holding_below_minimum() {
+	new_test_ext().execute_with(|| {
+		MinHolding::set(5);
+
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			10,
+			2
+		));
+
+		// Account 2 would keep 3 shares
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(2), id, 10, 1, None));
+		assert_noop!(
+			ProportionalAssetModule::buy_shares(Origin::signed(3), id, 7, 7, 2, Balance::MAX),
+			Error::<Test>::HoldingBelowMinimum
+		);
+		assert_noop!(
+			ProportionalAssetModule::transfer_shares_to_account(Origin::signed(2), id, 7, 3),
+			Error::<Test>::HoldingBelowMinimum
+		);
+
+		// Moving all of the shares is allowed
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(2),
+			id,
+			10,
+			3
+		));
+	});
+}
+
+#[test]
+fn burning_all_shares_empties_asset() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			40,
+			2
+		));
+		assert_ok!(ProportionalAssetModule::set_price_floor(Origin::signed(1), id, 1));
+		assert_ok!(ProportionalAssetModule::burn_shares(Origin::signed(2), id, 40));
+		assert_ok!(ProportionalAssetModule::burn_shares(Origin::signed(1), id, 60));
+
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::AssetEmptied(id)));
+		assert_eq!(ProportionalAssetModule::owner_count(&id), 0);
+		assert!(!ProportionalAssetToMainOwner::<Test>::contains_key(id));
+		assert_eq!(ProportionalAssetToBurnedShares::<Test>::get(id), 0);
+		assert!(!ProportionalAssetToPriceFloor::<Test>::contains_key(id));
+		assert_eq!(ProportionalAssetToDeposit::<Test>::get(id), None);
+
+		// The same data creates a fresh asset with the whole supply
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data,
+			1,
+			None,
+			None
+		));
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &1), 100);
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			10,
+			2
+		));
+		assert_ok!(ProportionalAssetModule::do_try_state());
+	});
+}
+
+#[test]
+fn burning_all_shares_refunds_the_deposit() {
+	new_test_ext().execute_with(|| {
+		AssetDeposit::set(10);
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+		assert_eq!(Balances::reserved_balance(1), 10);
+
+		assert_ok!(ProportionalAssetModule::burn_shares(Origin::signed(1), id, 100));
+
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 50);
+	});
+}
+
+#[test]
+fn buy_shares_failure_price_slippage() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 10, 2, None));
+
+		// The buyer expects to pay 2 per share, but the seller bumps the price first
+		let max_total_price = 2 * 5;
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 10, 3, None));
+
+		assert_noop!(
+			ProportionalAssetModule::buy_shares(Origin::signed(2), id, 5, 50, 1, max_total_price),
+			Error::<Test>::PriceSlippageExceeded
+		);
+		assert_ok!(ProportionalAssetModule::buy_shares(Origin::signed(2), id, 5, 50, 1, 15));
+	});
+}
+
+#[test]
+fn locked_shares_cannot_be_transferred_until_unlocked() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::lock_shares(Origin::signed(1), id, 80, 5));
+		assert_eq!(ProportionalAssetModule::spendable_shares(&id, &1), 20);
+
+		assert_noop!(
+			ProportionalAssetModule::transfer_shares_to_account(Origin::signed(1), id, 30, 2),
+			Error::<Test>::IncorrectSharesSelection
+		);
+		assert_noop!(
+			ProportionalAssetModule::offer_shares(Origin::signed(1), id, 30, 1, None),
+			Error::<Test>::OverCommittedOffers
+		);
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			20,
+			2
+		));
+
+		// The lock holds up to and including its block
+		System::set_block_number(5);
+		assert_noop!(
+			ProportionalAssetModule::unlock_shares(Origin::signed(1), id),
+			Error::<Test>::SharesStillLocked
+		);
+
+		System::set_block_number(6);
+		assert_ok!(ProportionalAssetModule::unlock_shares(Origin::signed(1), id));
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::SharesUnlocked(
+			id, 1, 80,
+		)));
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			30,
+			2
+		));
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &2), 50);
+	});
+}
+
+#[test]
+fn lock_shares_failure_not_enough_shares() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::lock_shares(Origin::signed(1), id, 60, 5));
+		assert_noop!(
+			ProportionalAssetModule::lock_shares(Origin::signed(1), id, 50, 5),
+			Error::<Test>::NotEnoughShares
+		);
+		assert_noop!(
+			ProportionalAssetModule::unlock_shares(Origin::signed(2), id),
+			Error::<Test>::NoLockedShares
+		);
+	});
+}
+
+#[test]
+fn owners_of_paged_returns_all_owners() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		for to in 2..=5 {
+			assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+				Origin::signed(1),
+				id,
+				10,
+				to
+			));
+		}
+
+		let (first, cursor) = ProportionalAssetModule::owners_of_paged(&id, None, 2);
+		assert_eq!(first.len(), 2);
+		assert!(cursor.is_some());
+
+		let (second, cursor) = ProportionalAssetModule::owners_of_paged(&id, cursor, 2);
+		assert_eq!(second.len(), 2);
+		assert!(cursor.is_some());
+
+		let (third, cursor) = ProportionalAssetModule::owners_of_paged(&id, cursor, 2);
+		assert_eq!(third.len(), 1);
+		assert_eq!(cursor, None);
+
+		let mut owners: Vec<_> = first.into_iter().chain(second).chain(third).collect();
+		owners.sort_by(|(a, _), (b, _)| a.cmp(b));
+		assert_eq!(owners, ProportionalAssetModule::owners_of(&id));
+	});
+}
+
+#[test]
+fn buyback_offered_success() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			40,
+			2
+		));
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(2), id, 20, 2, None));
+
+		assert_ok!(ProportionalAssetModule::buyback_offered(Origin::signed(1), id, 2, 15, 30));
+
+		System::assert_has_event(Event::ProportionalAssetModule(crate::Event::SharesBought {
+			id,
+			buyer: 1,
+			seller: 2,
+			shares: 15,
+			price_paid: 30,
+		}));
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &1), 75);
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &2), 25);
+		assert_eq!(Balances::free_balance(1), 20);
+		assert_eq!(Balances::free_balance(2), 80);
+	});
+}
+
+#[test]
+fn buyback_offered_failure_price_above_bound() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			40,
+			2
+		));
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(2), id, 20, 2, None));
+
+		// The seller re-offers at a higher price before the buyback is included
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(2), id, 20, 5, None));
+
+		assert_noop!(
+			ProportionalAssetModule::buyback_offered(Origin::signed(1), id, 2, 15, 30),
+			Error::<Test>::PriceSlippageExceeded
+		);
+	});
+}
+
+#[test]
+fn buyback_offered_failure_not_main_owner() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 20, 1, None));
+
+		assert_noop!(
+			ProportionalAssetModule::buyback_offered(Origin::signed(2), id, 1, 10, 10),
+			Error::<Test>::NotMainOwner
+		);
+	});
+}
+
+#[test]
+fn create_proportional_asset_failure_recreated_by_other_account() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data.clone());
+
+		assert_noop!(
+			ProportionalAssetModule::create_proportional_asset(
+				Origin::signed(2),
+				data,
+				1,
+				None,
+				None
+			),
+			Error::<Test>::AssetAlreadyExists
+		);
+		assert_eq!(ProportionalAssetToMainOwner::<Test>::get(id), Some(1));
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &1), 100);
+	});
+}
+
+#[test]
+fn assets_of_follows_holdings() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		let other_data: Vec<u8> = vec![4, 5, 6];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			other_data.clone(),
+			1,
+			None,
+			None
+		));
+		let other_id = get_hash_from_vec(other_data);
+
+		let mut assets = ProportionalAssetModule::assets_of(&1);
+		assets.sort();
+		let mut expected = vec![id, other_id];
+		expected.sort();
+		assert_eq!(assets, expected);
+		assert!(ProportionalAssetModule::assets_of(&2).is_empty());
+
+		// Transfer
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			40,
+			2
+		));
+		assert_eq!(ProportionalAssetModule::assets_of(&2), vec![id]);
+
+		// Buy
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(2), id, 40, 1, None));
+		assert_ok!(ProportionalAssetModule::buy_shares(
+			Origin::signed(3),
+			id,
+			10,
+			10,
+			2,
+			Balance::MAX
+		));
+		assert_eq!(ProportionalAssetModule::assets_of(&3), vec![id]);
+
+		// Full sell-off
+		assert_ok!(ProportionalAssetModule::buy_shares(
+			Origin::signed(3),
+			id,
+			30,
+			30,
+			2,
+			Balance::MAX
+		));
+		assert!(ProportionalAssetModule::assets_of(&2).is_empty());
+		assert_eq!(ProportionalAssetModule::assets_of(&3), vec![id]);
+	});
+}
+
+#[test]
+fn buy_shares_charges_marketplace_fee() {
+	new_test_ext().execute_with(|| {
+		MarketplaceFee::set(Permill::from_percent(1));
+
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 50, 3, None));
+
+		// 1% of 150 is 1.5, the fee is rounded down and the seller keeps the remainder
+		assert_ok!(ProportionalAssetModule::buy_shares(Origin::signed(3), id, 50, 150, 1, 150));
+
+		System::assert_has_event(Event::ProportionalAssetModule(crate::Event::FeeCharged(id, 1)));
+		assert_eq!(Balances::free_balance(FeeCollector::get()), 1);
+		assert_eq!(Balances::free_balance(1), 50 + 149);
+		assert_eq!(Balances::free_balance(3), 1_000 - 150);
+	});
+}
+
+#[test]
+fn orders_and_bids_charge_marketplace_fee() {
+	new_test_ext().execute_with(|| {
+		MarketplaceFee::set(Permill::from_percent(10));
+
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::place_order(Origin::signed(1), id, 10, 10));
+		assert_ok!(ProportionalAssetModule::fill_order(Origin::signed(3), id, 0, 10));
+		System::assert_has_event(Event::ProportionalAssetModule(crate::Event::FeeCharged(id, 10)));
+		assert_eq!(Balances::free_balance(FeeCollector::get()), 10);
+		assert_eq!(Balances::free_balance(1), 50 + 90);
+		assert_eq!(Balances::free_balance(3), 1_000 - 100);
+
+		assert_ok!(ProportionalAssetModule::make_bid(Origin::signed(3), id, 1, 10, 5));
+		assert_ok!(ProportionalAssetModule::accept_bid(Origin::signed(1), id, 3));
+		assert_eq!(Balances::free_balance(FeeCollector::get()), 10 + 5);
+		assert_eq!(Balances::free_balance(1), 50 + 90 + 45);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::free_balance(3), 1_000 - 100 - 50);
+	});
+}
+
+#[test]
+fn asset_info_aggregates_state() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		let id = get_hash_from_vec(data.clone());
+		assert_eq!(ProportionalAssetModule::asset_info(&id), None);
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data,
+			1,
+			None,
+			None
+		));
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			30,
+			2
+		));
+		assert_ok!(ProportionalAssetModule::burn_shares(Origin::signed(2), id, 10));
+		assert_ok!(ProportionalAssetModule::set_frozen(Origin::signed(1), id, true));
+
+		assert_eq!(
+			ProportionalAssetModule::asset_info(&id),
+			Some(AssetInfo {
+				main_owner: Some(1),
+				owner_count: 2,
+				effective_supply: 90,
+				frozen: true
+			})
+		);
+	});
+}
+
+#[test]
+fn buy_shares_failure_price_overflow() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		// Without a maximum share price the price of the shares can overflow
+		MaxSharePrice::set(Balance::MAX);
+		assert_ok!(ProportionalAssetModule::offer_shares(
+			Origin::signed(1),
+			id,
+			50,
+			Balance::MAX / 2,
+			None
+		));
+
+		// The saturated price would have been accepted for an amount far below the real one
+		assert_noop!(
+			ProportionalAssetModule::buy_shares(
+				Origin::signed(3),
+				id,
+				3,
+				Balance::MAX,
+				1,
+				Balance::MAX
+			),
+			Error::<Test>::PriceOverflow
+		);
+	});
+}
+
+#[test]
+fn split_asset_success() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			10,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		let data_a: Vec<u8> = vec![4, 5, 6];
+		let data_b: Vec<u8> = vec![7, 8, 9];
+		assert_ok!(ProportionalAssetModule::split_asset(
+			Origin::signed(1),
+			id,
+			data_a.clone(),
+			data_b.clone(),
+			Permill::from_percent(35)
+		));
+		let id_a = get_hash_from_vec(data_a);
+		let id_b = get_hash_from_vec(data_b);
+
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::AssetSplit(
+			id, id_a, id_b,
+		)));
+		assert_eq!(ProportionalAssetModule::asset_info(&id), None);
+		assert_eq!(ProportionalAssetModule::total_accounted_shares(&id_a), 100);
+		assert_eq!(ProportionalAssetModule::total_accounted_shares(&id_b), 100);
+		assert_eq!(ProportionalAssetToMainOwner::<Test>::get(id_a), Some(1));
+		assert_eq!(ProportionalAssetToMainOwner::<Test>::get(id_b), Some(1));
+
+		// 35% of the price of 10 is rounded down
+		assert_eq!(ProportionalAssetToOwnerToMetadata::<Test>::get(id_a, 1).unwrap().price, 3);
+		assert_eq!(ProportionalAssetToOwnerToMetadata::<Test>::get(id_b, 1).unwrap().price, 7);
+	});
+}
+
+#[test]
+fn split_asset_failure_multiple_owners() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			10,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			10,
+			2
+		));
+
+		assert_noop!(
+			ProportionalAssetModule::split_asset(
+				Origin::signed(1),
+				id,
+				vec![4, 5, 6],
+				vec![7, 8, 9],
+				Permill::from_percent(50)
+			),
+			Error::<Test>::AssetHasMultipleOwners
+		);
+	});
+}
+
+#[test]
+fn merge_assets_success() {
+	new_test_ext().execute_with(|| {
+		let data_a: Vec<u8> = vec![1, 2, 3];
+		let data_b: Vec<u8> = vec![4, 5, 6];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data_a.clone(),
+			3,
+			None,
+			None
+		));
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data_b.clone(),
+			7,
+			None,
+			None
+		));
+		let id_a = get_hash_from_vec(data_a);
+		let id_b = get_hash_from_vec(data_b);
+
+		let merged_data: Vec<u8> = vec![7, 8, 9];
+		assert_ok!(ProportionalAssetModule::merge_assets(
+			Origin::signed(1),
+			id_a,
+			id_b,
+			merged_data.clone()
+		));
+		let merged_id = get_hash_from_vec(merged_data);
+
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::AssetsMerged(
+			id_a, id_b, merged_id,
+		)));
+		assert_eq!(ProportionalAssetModule::asset_info(&id_a), None);
+		assert_eq!(ProportionalAssetModule::asset_info(&id_b), None);
+		assert_eq!(ProportionalAssetModule::shares_of(&merged_id, &1), 100);
+		assert_eq!(ProportionalAssetToMainOwner::<Test>::get(merged_id), Some(1));
+		assert_eq!(
+			ProportionalAssetToOwnerToMetadata::<Test>::get(merged_id, 1).unwrap().price,
+			10
+		);
+	});
+}
+
+#[test]
+fn merge_assets_failure_multiple_owners() {
+	new_test_ext().execute_with(|| {
+		let data_a: Vec<u8> = vec![1, 2, 3];
+		let data_b: Vec<u8> = vec![4, 5, 6];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data_a.clone(),
+			1,
+			None,
+			None
+		));
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data_b.clone(),
+			1,
+			None,
+			None
+		));
+		let id_a = get_hash_from_vec(data_a);
+		let id_b = get_hash_from_vec(data_b);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id_b,
+			10,
+			2
+		));
+
+		assert_noop!(
+			ProportionalAssetModule::merge_assets(Origin::signed(1), id_a, id_b, vec![7, 8, 9]),
+			Error::<Test>::AssetHasMultipleOwners
+		);
+	});
+}
+
+#[test]
+fn vote_majority_passes_proposal() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			40,
+			2
+		));
+
+		assert_ok!(ProportionalAssetModule::create_proposal(
+			Origin::signed(2),
+			id,
+			H256::repeat_byte(1)
+		));
+		assert_ok!(ProportionalAssetModule::vote(Origin::signed(2), id, 0, false));
+		assert_ok!(ProportionalAssetModule::vote(Origin::signed(1), id, 0, true));
+
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::ProposalPassed(
+			id, 0,
+		)));
+		assert!(!Proposals::<Test>::contains_key(id, 0));
+	});
+}
+
+#[test]
+fn vote_weight_is_snapshotted_when_the_proposal_is_created() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			40,
+			2
+		));
+		assert_ok!(ProportionalAssetModule::create_proposal(
+			Origin::signed(2),
+			id,
+			H256::repeat_byte(1)
+		));
+
+		// The shares moved after voting cannot vote again from another account
+		assert_ok!(ProportionalAssetModule::vote(Origin::signed(2), id, 0, true));
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(2),
+			id,
+			40,
+			3
+		));
+		assert_noop!(
+			ProportionalAssetModule::vote(Origin::signed(3), id, 0, true),
+			Error::<Test>::NotAnOwner
+		);
+
+		// The main owner still votes with the shares held at creation
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			30,
+			3
+		));
+		assert_ok!(ProportionalAssetModule::vote(Origin::signed(1), id, 0, false));
+		System::assert_has_event(Event::ProportionalAssetModule(crate::Event::Voted {
+			id,
+			proposal_id: 0,
+			voter: 1,
+			approve: false,
+			shares: 60,
+		}));
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::ProposalRejected(
+			id, 0,
+		)));
+	});
+}
+
+#[test]
+fn vote_minority_does_not_pass_proposal() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			30,
+			2
+		));
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			30,
+			3
+		));
+
+		assert_ok!(ProportionalAssetModule::create_proposal(
+			Origin::signed(2),
+			id,
+			H256::repeat_byte(1)
+		));
+		assert_ok!(ProportionalAssetModule::vote(Origin::signed(2), id, 0, true));
+		assert_ok!(ProportionalAssetModule::vote(Origin::signed(1), id, 0, false));
+
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::Voted {
+			id,
+			proposal_id: 0,
+			voter: 1,
+			approve: false,
+			shares: 40,
+		}));
+		assert!(Proposals::<Test>::contains_key(id, 0));
+		assert_eq!(ProposalTallies::<Test>::get(id, 0), Tally { ayes: 30, nays: 40 });
+
+		assert_noop!(
+			ProportionalAssetModule::vote(Origin::signed(2), id, 0, true),
+			Error::<Test>::AlreadyVoted
+		);
+	});
+}
+
+#[test]
+fn mutating_calls_fail_for_nonexistent_asset() {
+	new_test_ext().execute_with(|| {
+		let id = get_hash_from_vec(vec![1, 2, 3]);
+		let other_id = get_hash_from_vec(vec![4, 5, 6]);
+		let origin = || Origin::signed(1);
+
+		let calls: Vec<Box<dyn Fn() -> DispatchResult>> = vec![
+			Box::new(move || ProportionalAssetModule::offer_shares(origin(), id, 10, 1, None)),
+			Box::new(move || {
+				ProportionalAssetModule::transfer_shares_to_account(origin(), id, 10, 2)
+			}),
+			Box::new(move || {
+				ProportionalAssetModule::buy_shares(origin(), id, 10, 10, 2, Balance::MAX)
+					.map(|_| ())
+					.map_err(|e| e.error)
+			}),
+			Box::new(move || ProportionalAssetModule::claim_onwership(origin(), id)),
+			Box::new(move || ProportionalAssetModule::burn_shares(origin(), id, 10)),
+			Box::new(move || ProportionalAssetModule::destroy_asset(origin(), id)),
+			Box::new(move || ProportionalAssetModule::set_offer_allowlist(origin(), id, vec![2])),
+			Box::new(move || {
+				ProportionalAssetModule::transfer_shares_batch(origin(), id, vec![(2, 10)])
+			}),
+			Box::new(move || ProportionalAssetModule::set_price_floor(origin(), id, 1)),
+			Box::new(move || ProportionalAssetModule::increase_offer(origin(), id, 10)),
+			Box::new(move || ProportionalAssetModule::distribute_dividend(origin(), id, 10)),
+			Box::new(move || ProportionalAssetModule::transfer_main_ownership(origin(), id, 2)),
+			Box::new(move || ProportionalAssetModule::set_frozen(origin(), id, true)),
+			Box::new(move || ProportionalAssetModule::place_order(origin(), id, 10, 1)),
+			Box::new(move || ProportionalAssetModule::cancel_order(origin(), id, 0)),
+			Box::new(move || {
+				ProportionalAssetModule::fill_order(origin(), id, 0, 10)
+					.map(|_| ())
+					.map_err(|e| e.error)
+			}),
+			Box::new(move || ProportionalAssetModule::lock_shares(origin(), id, 10, 5)),
+			Box::new(move || ProportionalAssetModule::unlock_shares(origin(), id)),
+			Box::new(move || ProportionalAssetModule::buyback_offered(origin(), id, 2, 10, 10)),
+			Box::new(move || {
+				ProportionalAssetModule::split_asset(
+					origin(),
+					id,
+					vec![7],
+					vec![8],
+					Permill::from_percent(50),
+				)
+			}),
+			Box::new(move || {
+				ProportionalAssetModule::merge_assets(origin(), id, other_id, vec![9])
+			}),
+			Box::new(move || {
+				ProportionalAssetModule::create_proposal(origin(), id, H256::repeat_byte(1))
+			}),
+			Box::new(move || ProportionalAssetModule::vote(origin(), id, 0, true)),
+		];
+
+		for call in calls {
+			assert_noop!(call(), Error::<Test>::AssetDoesNotExist);
+		}
+	});
+}
+
+#[test]
+fn make_bid_reserves_price() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::make_bid(Origin::signed(3), id, 1, 20, 5));
+
+		assert_eq!(Balances::reserved_balance(3), 100);
+		assert_eq!(Balances::free_balance(3), 900);
+		assert_noop!(
+			ProportionalAssetModule::make_bid(Origin::signed(3), id, 1, 10, 5),
+			Error::<Test>::BidAlreadyExists
+		);
+		assert_noop!(
+			ProportionalAssetModule::make_bid(Origin::signed(2), id, 1, 20, 5),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn accept_bid_settles_from_reserve() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::make_bid(Origin::signed(3), id, 1, 20, 5));
+		assert_ok!(ProportionalAssetModule::accept_bid(Origin::signed(1), id, 3));
+
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::BidAccepted {
+			id,
+			seller: 1,
+			bidder: 3,
+			shares: 20,
+			price: 5,
+		}));
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &3), 20);
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &1), 80);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::free_balance(3), 900);
+		assert_eq!(Balances::free_balance(1), 150);
+		assert_noop!(
+			ProportionalAssetModule::accept_bid(Origin::signed(1), id, 3),
+			Error::<Test>::BidNotFound
+		);
+	});
+}
+
+#[test]
+fn cancel_bid_refunds_reserve() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::make_bid(Origin::signed(3), id, 1, 20, 5));
+		assert_ok!(ProportionalAssetModule::cancel_bid(Origin::signed(3), id, 1));
+
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::free_balance(3), 1_000);
+		assert!(!Bids::<Test>::contains_key((id, 1, 3)));
+	});
+}
+
+#[test]
+fn offer_shares_price_change_within_bounds() {
+	new_test_ext().execute_with(|| {
+		MaxPriceChange::set(Permill::from_percent(10));
+
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			10,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 10, 11, None));
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 10, 10, None));
+	});
+}
+
+#[test]
+fn offer_shares_failure_price_change_too_large() {
+	new_test_ext().execute_with(|| {
+		MaxPriceChange::set(Permill::from_percent(10));
+
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			10,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_noop!(
+			ProportionalAssetModule::offer_shares(Origin::signed(1), id, 10, 12, None),
+			Error::<Test>::PriceChangeTooLarge
+		);
+		assert_noop!(
+			ProportionalAssetModule::offer_shares(Origin::signed(1), id, 10, 8, None),
+			Error::<Test>::PriceChangeTooLarge
+		);
+	});
+}
+
+#[test]
+fn main_owner_changes_are_notified() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		// The main owner slot is vacated once the main owner loses the majority
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			60,
+			2
+		));
+		assert_ok!(ProportionalAssetModule::claim_onwership(Origin::signed(2), id));
+		assert_eq!(MainOwnerChanges::get(), vec![(None, 2, id)]);
+
+		// A stale main owner record hands the main ownership over
+		ProportionalAssetToMainOwner::<Test>::insert(id, 1);
+		assert_ok!(ProportionalAssetModule::transfer_main_ownership(Origin::signed(1), id, 2));
+		assert_eq!(MainOwnerChanges::get(), vec![(None, 2, id), (Some(1), 2, id)]);
+	});
+}
+
+#[test]
+fn buy_shares_success_amount_above_u64() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 10, 2, None));
+
+		// The amount sent is compared as a balance, it is never converted to u64
+		let amount = u64::MAX as u128 * 1_000;
+		assert_ok!(ProportionalAssetModule::buy_shares(
+			Origin::signed(3),
+			id,
+			10,
+			amount,
+			1,
+			Balance::MAX
+		));
+
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &3), 10);
+		assert_eq!(Balances::free_balance(3), 1_000 - 20);
+	});
+}
+
+#[test]
+fn buy_shares_success_price_above_u64() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		// The share price is a balance, it is never converted to u64
+		let share_price = u64::MAX as Balance + 1;
+		assert_ok!(ProportionalAssetModule::offer_shares(
+			Origin::signed(1),
+			id,
+			2,
+			share_price,
+			None
+		));
+		assert_ok!(Balances::set_balance(Origin::root(), 3, share_price * 4, 0));
+		assert_eq!(ProportionalAssetModule::quote_buy(&id, &3, &1, 2), Ok(share_price * 2));
+		assert_ok!(ProportionalAssetModule::buy_shares(
+			Origin::signed(3),
+			id,
+			2,
+			share_price * 2,
+			1,
+			share_price * 2
+		));
+
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &3), 2);
+		assert_eq!(Balances::free_balance(3), share_price * 2);
+	});
+}
+
+#[test]
+fn quote_buy_matches_the_amount_paid() {
+	new_test_ext().execute_with(|| {
+		MarketplaceFee::set(Permill::from_percent(1));
+
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_eq!(
+			ProportionalAssetModule::quote_buy(&id, &3, &1, 10),
+			Err(Error::<Test>::IncorrectSeller)
+		);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 50, 3, None));
+
+		assert_eq!(
+			ProportionalAssetModule::quote_buy(&id, &3, &1, 51),
+			Err(Error::<Test>::IncorrectSharesSelection)
+		);
+		assert_eq!(
+			ProportionalAssetModule::quote_buy(&id, &3, &2, 10),
+			Err(Error::<Test>::IncorrectSeller)
+		);
+
+		assert_eq!(
+			ProportionalAssetModule::quote_buy(&id, &1, &1, 10),
+			Err(Error::<Test>::IncorrectSeller)
+		);
+
+		let quote = ProportionalAssetModule::quote_buy(&id, &3, &1, 50).unwrap();
+		assert_eq!(quote, 150);
+
+		let balance_before = Balances::free_balance(3);
+		assert_ok!(ProportionalAssetModule::buy_shares(Origin::signed(3), id, 50, 1_000, 1, 1_000));
+		assert_eq!(balance_before - Balances::free_balance(3), quote);
+	});
+}
+
+#[test]
+fn set_status_transitions_and_blocks_buys() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+		assert_eq!(ProportionalAssetModule::status_of(&id), AssetStatus::Listed);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 20, 1, None));
+
+		assert_ok!(ProportionalAssetModule::set_status(
+			Origin::signed(1),
+			id,
+			AssetStatus::UnderContract
+		));
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::StatusChanged(
+			id,
+			AssetStatus::UnderContract,
+		)));
+		assert_eq!(ProportionalAssetModule::status_of(&id), AssetStatus::UnderContract);
+
+		assert_noop!(
+			ProportionalAssetModule::buy_shares(Origin::signed(2), id, 10, 10, 1, Balance::MAX),
+			free_rejection(Error::<Test>::AssetNotListed)
+		);
+
+		assert_ok!(ProportionalAssetModule::set_status(Origin::signed(1), id, AssetStatus::Listed));
+		assert_eq!(ProportionalAssetModule::status_of(&id), AssetStatus::Listed);
+		assert_ok!(ProportionalAssetModule::buy_shares(
+			Origin::signed(2),
+			id,
+			10,
+			10,
+			1,
+			Balance::MAX
+		));
+
+		assert_ok!(ProportionalAssetModule::set_status(Origin::signed(1), id, AssetStatus::Sold));
+		assert_eq!(ProportionalAssetModule::status_of(&id), AssetStatus::Sold);
+	});
+}
+
+#[test]
+fn set_status_failure_not_main_owner() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_noop!(
+			ProportionalAssetModule::set_status(Origin::signed(2), id, AssetStatus::Delisted),
+			Error::<Test>::NotMainOwner
+		);
+	});
+}
+
+#[test]
+fn approve_shares_sets_allowance() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::approve_shares(Origin::signed(1), id, 2, 30));
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::Approval {
+			id,
+			owner: 1,
+			spender: 2,
+			amount: 30,
+		}));
+		assert_eq!(ProportionalAssetAllowance::<Test>::get((id, 1, 2)), 30);
+
+		assert_ok!(ProportionalAssetModule::approve_shares(Origin::signed(1), id, 2, 0));
+		assert!(!ProportionalAssetAllowance::<Test>::contains_key((id, 1, 2)));
+
+		// Only an owner can approve a spender
+		assert_noop!(
+			ProportionalAssetModule::approve_shares(Origin::signed(3), id, 2, 30),
+			Error::<Test>::InvalidAccount
+		);
+	});
+}
+
+#[test]
+fn transfer_shares_from_within_allowance() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::approve_shares(Origin::signed(1), id, 2, 30));
+		assert_ok!(ProportionalAssetModule::transfer_shares_from(Origin::signed(2), id, 1, 3, 20));
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::SharesTransferred(
+			id, 1, 3, 20,
+		)));
+
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &1), 80);
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &3), 20);
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &2), 0);
+		assert_eq!(ProportionalAssetAllowance::<Test>::get((id, 1, 2)), 10);
+	});
+}
+
+#[test]
+fn transfer_shares_from_failure_over_allowance() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::approve_shares(Origin::signed(1), id, 2, 30));
+		assert_noop!(
+			ProportionalAssetModule::transfer_shares_from(Origin::signed(2), id, 1, 3, 31),
+			Error::<Test>::InsufficientAllowance
+		);
+		assert_noop!(
+			ProportionalAssetModule::transfer_shares_from(Origin::signed(3), id, 1, 3, 1),
+			Error::<Test>::InsufficientAllowance
+		);
+	});
+}
+
+#[test]
+fn distribute_proportionally_sums_to_total() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		// 34/33/33 split between accounts 1, 2 and 3
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			33,
+			2
+		));
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			33,
+			3
+		));
+
+		// Amounts past the range of shares are split as precisely
+		for total in [1, 2, 10, 100, 101, 1_000, 999_999, u64::MAX as u128 * 3 + 1] {
+			let cuts = ProportionalAssetModule::distribute_proportionally(&id, total);
+			assert_eq!(cuts.iter().map(|(_, cut)| cut).sum::<u128>(), total);
+		}
+
+		// 3.4, 3.3 and 3.3 are rounded down to 3 each and the largest remainder gets the dust
+		assert_eq!(
+			ProportionalAssetModule::distribute_proportionally(&id, 10),
+			vec![(1, 4), (2, 3), (3, 3)]
+		);
+		// 0.68 and 0.66 twice, the ties go to the first owner
+		assert_eq!(
+			ProportionalAssetModule::distribute_proportionally(&id, 2),
+			vec![(1, 1), (2, 1), (3, 0)]
+		);
+	});
+}
+
+#[test]
+fn transfer_below_minimum_fails() {
+	new_test_ext().execute_with(|| {
+		MinShareTransfer::set(10);
+
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_noop!(
+			ProportionalAssetModule::transfer_shares_to_account(Origin::signed(1), id, 9, 2),
+			Error::<Test>::TransferBelowMinimum
+		);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 20, 1, None));
+		assert_noop!(
+			ProportionalAssetModule::buy_shares(Origin::signed(2), id, 9, 9, 1, Balance::MAX),
+			Error::<Test>::TransferBelowMinimum
+		);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			10,
+			2
+		));
+	});
+}
+
+#[test]
+fn transfer_of_whole_holding_below_minimum_succeeds() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			5,
+			2
+		));
+
+		MinShareTransfer::set(10);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(2),
+			id,
+			5,
+			3
+		));
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &2), 0);
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &3), 5);
+	});
+}
+
+#[test]
+fn trade_volume_accumulates_paid_amounts() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+		assert_eq!(ProportionalAssetModule::trade_volume_of(&id), 0);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 50, 3, None));
+		assert_ok!(ProportionalAssetModule::buy_shares(
+			Origin::signed(2),
+			id,
+			10,
+			30,
+			1,
+			Balance::MAX
+		));
+		assert_ok!(ProportionalAssetModule::buy_shares(
+			Origin::signed(3),
+			id,
+			20,
+			60,
+			1,
+			Balance::MAX
+		));
+
+		assert_eq!(ProportionalAssetModule::trade_volume_of(&id), 30 + 60);
+	});
+}
+
+#[test]
+fn force_destroy_asset_as_root() {
+	new_test_ext().execute_with(|| {
+		AssetDeposit::set(10);
+
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			40,
+			2
+		));
+		assert_eq!(Balances::reserved_balance(1), 10);
+
+		assert_ok!(ProportionalAssetModule::force_destroy_asset(Origin::root(), id));
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::AssetDestroyed(id)));
+
+		assert!(!ProportionalAssetModule::asset_exists(&id));
+		assert_eq!(ProportionalAssetToMainOwner::<Test>::get(id), None);
+		assert_eq!(ProportionalAssetToOwnerToMetadata::<Test>::get(id, 1), None);
+		assert_eq!(ProportionalAssetToOwnerToMetadata::<Test>::get(id, 2), None);
+		assert!(ProportionalAssetModule::assets_of(&2).is_empty());
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn force_destroy_asset_failure_signed_origin() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_noop!(
+			ProportionalAssetModule::force_destroy_asset(Origin::signed(1), id),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn market_depth_sums_offers_and_finds_best_price() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+		assert_eq!(ProportionalAssetModule::market_depth(&id), (0, None));
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			40,
+			2
+		));
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 20, 5, None));
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(2), id, 10, 3, Some(3)));
+
+		assert_eq!(ProportionalAssetModule::market_depth(&id), (30, Some(3)));
+
+		// An expired offer cannot be bought anymore
+		System::set_block_number(4);
+		assert_eq!(ProportionalAssetModule::market_depth(&id), (20, Some(5)));
+	});
+}
+
+#[test]
+fn shares_arithmetic_saturates() {
+	assert_eq!(Shares(u64::MAX).saturating_add(Shares(1)), Shares(u64::MAX));
+	assert_eq!(Shares(1).saturating_sub(Shares(2)), Shares(0));
+	assert_eq!(Shares(5).saturating_sub(Shares(2)), Shares(3));
+	assert_eq!(Shares(u64::MAX).checked_add(Shares(1)), None);
+	assert_eq!(Shares(1).checked_sub(Shares(2)), None);
+	assert_eq!(Shares(2).checked_add(Shares(3)), Some(Shares(5)));
+	assert_eq!(Shares(7).min(Shares(4)), Shares(4));
+	assert!(Shares::zero().is_zero());
+	assert_eq!(u64::from(Shares::from(42)), 42);
+}
+
+#[test]
+fn shares_clamp_to_caps_the_amount() {
+	assert_eq!(Shares(7).clamp_to(Shares(4)), Shares(4));
+	assert_eq!(Shares(3).clamp_to(Shares(4)), Shares(3));
+	assert_eq!(Shares(4).clamp_to(Shares(4)), Shares(4));
+	assert_eq!(Shares(u64::MAX).clamp_to(Shares::zero()), Shares::zero());
+}
+
+#[test]
+fn shares_are_encoded_like_u64() {
+	use codec::{Decode, Encode};
+
+	assert_eq!(Shares(1_234).encode(), 1_234u64.encode());
+	assert_eq!(Shares::decode(&mut &1_234u64.encode()[..]), Ok(Shares(1_234)));
+
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 20, 3, None));
+
+		// The stored metadata keeps the layout of three plain integers
+		let raw = frame_support::storage::unhashed::get_raw(&ProportionalAssetToOwnerToMetadata::<
+			Test,
+		>::hashed_key_for(id, 1))
+		.unwrap();
+		assert_eq!(raw, (20u64, 100u64, 3u64).encode());
+		assert_eq!(
+			ProportionalAssetToOwnerToMetadata::<Test>::get(id, 1),
+			Some(crate::MetaData { offers: Shares(20), shares: Shares(100), price: 3 })
+		);
+	});
+}
+
+#[test]
+fn claim_onwership_moves_deposit() {
+	new_test_ext().execute_with(|| {
+		AssetDeposit::set(10);
+
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+		assert_eq!(Balances::reserved_balance(1), 10);
+
+		// The main owner slot is vacated once account 1 no longer holds a majority
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			60,
+			2
+		));
+		assert_ok!(ProportionalAssetModule::claim_onwership(Origin::signed(2), id));
+
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 10);
+
+		// The deposit is returned to the new main owner on destruction
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			40,
+			2
+		));
+		assert_ok!(ProportionalAssetModule::destroy_asset(Origin::signed(2), id));
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
+
+#[test]
+fn claim_onwership_failure_cannot_cover_deposit() {
+	new_test_ext().execute_with(|| {
+		AssetDeposit::set(60);
+
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(3),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(3),
+			id,
+			60,
+			2
+		));
+
+		assert_noop!(
+			ProportionalAssetModule::claim_onwership(Origin::signed(2), id),
+			Error::<Test>::InsufficientBalance
+		);
+		assert_eq!(Balances::reserved_balance(3), 60);
+	});
+}
+
+#[test]
+fn metadata_uri_set_on_creation_and_by_main_owner() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			Some(b"ipfs://deed".to_vec())
+		));
+		let id = get_hash_from_vec(data);
+		assert_eq!(ProportionalAssetModule::metadata_uri_of(&id), Some(b"ipfs://deed".to_vec()));
+
+		assert_ok!(ProportionalAssetModule::set_metadata_uri(
+			Origin::signed(1),
+			id,
+			b"ipfs://title".to_vec()
+		));
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::MetadataUriSet(id)));
+		assert_eq!(ProportionalAssetModule::metadata_uri_of(&id), Some(b"ipfs://title".to_vec()));
+
+		assert_ok!(ProportionalAssetModule::set_metadata_uri(Origin::signed(1), id, vec![]));
+		assert_eq!(ProportionalAssetModule::metadata_uri_of(&id), None);
+	});
+}
+
+#[test]
+fn set_metadata_uri_failure() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_noop!(
+			ProportionalAssetModule::set_metadata_uri(
+				Origin::signed(2),
+				id,
+				b"ipfs://deed".to_vec()
+			),
+			Error::<Test>::NotMainOwner
+		);
+		assert_noop!(
+			ProportionalAssetModule::set_metadata_uri(Origin::signed(1), id, vec![1; 33]),
+			Error::<Test>::UriTooLong
+		);
+		assert_noop!(
+			ProportionalAssetModule::create_proportional_asset(
+				Origin::signed(1),
+				vec![4, 5, 6],
+				1,
+				None,
+				Some(vec![1; 33])
+			),
+			Error::<Test>::UriTooLong
+		);
+	});
+}
+
+#[test]
+fn buy_shares_keeps_offer_of_buyer() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			30,
+			2
+		));
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(2), id, 10, 7, None));
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 20, 1, None));
+
+		assert_ok!(ProportionalAssetModule::buy_shares(
+			Origin::signed(2),
+			id,
+			10,
+			10,
+			1,
+			Balance::MAX
+		));
+
+		assert_eq!(
+			ProportionalAssetToOwnerToMetadata::<Test>::get(id, 2),
+			Some(crate::MetaData { offers: Shares(10), shares: Shares(40), price: 7 })
+		);
+	});
+}
+
+#[test]
+fn transfer_above_holding_cap_fails() {
+	new_test_ext().execute_with(|| {
+		MaxHoldingPercent::set(Permill::from_percent(30));
+
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_noop!(
+			ProportionalAssetModule::transfer_shares_to_account(Origin::signed(1), id, 31, 2),
+			Error::<Test>::HoldingCapExceeded
+		);
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			30,
+			2
+		));
+
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 20, 1, None));
+		assert_noop!(
+			ProportionalAssetModule::buy_shares(Origin::signed(2), id, 1, 1, 1, Balance::MAX),
+			Error::<Test>::HoldingCapExceeded
+		);
+		assert_ok!(ProportionalAssetModule::buy_shares(
+			Origin::signed(3),
+			id,
+			10,
+			10,
+			1,
+			Balance::MAX
+		));
+	});
+}
+
+#[test]
+fn main_owner_can_be_exempt_from_holding_cap() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			20,
+			2
+		));
+
+		MaxHoldingPercent::set(Permill::from_percent(30));
+		assert_noop!(
+			ProportionalAssetModule::transfer_shares_to_account(Origin::signed(2), id, 10, 1),
+			Error::<Test>::HoldingCapExceeded
+		);
+
+		MainOwnerExemptFromCap::set(true);
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(2),
+			id,
+			10,
+			1
+		));
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &1), 90);
+	});
+}
+
+#[test]
+fn recover_main_owner_fills_vacated_slot() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			60,
+			2
+		));
+		assert_eq!(ProportionalAssetToMainOwner::<Test>::get(id), None);
+
+		assert_noop!(
+			ProportionalAssetModule::recover_main_owner(Origin::signed(1), id),
+			Error::<Test>::NotEnoughShares
+		);
+
+		assert_ok!(ProportionalAssetModule::recover_main_owner(Origin::signed(2), id));
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::MainOwnerSet(
+			2, id,
+		)));
+		assert_eq!(ProportionalAssetToMainOwner::<Test>::get(id), Some(2));
+	});
+}
+
+#[test]
+fn recover_main_owner_failure_main_owner_exists() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_noop!(
+			ProportionalAssetModule::recover_main_owner(Origin::signed(1), id),
+			Error::<Test>::MainOwnerExists
+		);
+	});
+}
+
+#[test]
+fn swap_shares_works() {
+	new_test_ext().execute_with(|| {
+		let data_a: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data_a.clone(),
+			1,
+			None,
+			None
+		));
+		let id_a = get_hash_from_vec(data_a);
+
+		let data_b: Vec<u8> = vec![4, 5, 6];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(2),
+			data_b.clone(),
+			1,
+			None,
+			None
+		));
+		let id_b = get_hash_from_vec(data_b);
+
+		assert_noop!(
+			ProportionalAssetModule::swap_shares(Origin::signed(1), id_a, 20, 2, id_b, 10),
+			Error::<Test>::SwapIntentNotFound
+		);
+
+		assert_ok!(ProportionalAssetModule::register_swap_intent(
+			Origin::signed(2),
+			1,
+			id_b,
+			10,
+			id_a,
+			20
+		));
+		assert_eq!(
+			SwapIntents::<Test>::get(2, 1),
+			Some(SwapIntent { give_id: id_b, give_shares: 10, take_id: id_a, take_shares: 20 })
+		);
+
+		assert_noop!(
+			ProportionalAssetModule::swap_shares(Origin::signed(1), id_a, 15, 2, id_b, 10),
+			Error::<Test>::SwapIntentMismatch
+		);
+
+		assert_ok!(ProportionalAssetModule::swap_shares(Origin::signed(1), id_a, 20, 2, id_b, 10));
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::SharesSwapped {
+			who: 1,
+			counterparty: 2,
+			id_a,
+			shares_a: 20,
+			id_b,
+			shares_b: 10,
+		}));
+
+		assert_eq!(ProportionalAssetModule::shares_of(&id_a, &1), 80);
+		assert_eq!(ProportionalAssetModule::shares_of(&id_a, &2), 20);
+		assert_eq!(ProportionalAssetModule::shares_of(&id_b, &2), 90);
+		assert_eq!(ProportionalAssetModule::shares_of(&id_b, &1), 10);
+		assert_eq!(SwapIntents::<Test>::get(2, 1), None);
+	});
+}
+
+#[test]
+fn swap_shares_rolls_back_when_counterparty_lacks_shares() {
+	new_test_ext().execute_with(|| {
+		let data_a: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data_a.clone(),
+			1,
+			None,
+			None
+		));
+		let id_a = get_hash_from_vec(data_a);
+
+		let data_b: Vec<u8> = vec![4, 5, 6];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(2),
+			data_b.clone(),
+			1,
+			None,
+			None
+		));
+		let id_b = get_hash_from_vec(data_b);
+
+		assert_ok!(ProportionalAssetModule::register_swap_intent(
+			Origin::signed(2),
+			1,
+			id_b,
+			TotalSupply::get() + 1,
+			id_a,
+			20
+		));
+
+		// The shares of the origin are moved first and restored with the failed swap
+		assert_noop!(
+			ProportionalAssetModule::swap_shares(
+				Origin::signed(1),
+				id_a,
+				20,
+				2,
+				id_b,
+				TotalSupply::get() + 1
+			),
+			Error::<Test>::IncorrectSharesSelection
+		);
+		assert_eq!(ProportionalAssetModule::shares_of(&id_a, &1), 100);
+		assert!(SwapIntents::<Test>::contains_key(2, 1));
+	});
+}
+
+#[test]
+fn cancel_swap_intent_works() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_noop!(
+			ProportionalAssetModule::cancel_swap_intent(Origin::signed(2), 1),
+			Error::<Test>::SwapIntentNotFound
+		);
+
+		assert_ok!(ProportionalAssetModule::register_swap_intent(
+			Origin::signed(2),
+			1,
+			id,
+			1,
+			id,
+			1
+		));
+		assert_ok!(ProportionalAssetModule::cancel_swap_intent(Origin::signed(2), 1));
+		assert_eq!(SwapIntents::<Test>::get(2, 1), None);
+	});
+}
+
+#[test]
+fn salted_asset_ids_are_distinct_per_creator() {
+	new_test_ext().execute_with(|| {
+		SaltedAssetIds::set(true);
+
+		let data: Vec<u8> = vec![1, 2, 3];
+		let id_1 = ProportionalAssetModule::next_asset_id(&1, &data);
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+
+		let id_2 = ProportionalAssetModule::next_asset_id(&2, &data);
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(2),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+
+		assert_ne!(id_1, id_2);
+		assert_ne!(id_1, get_hash_from_vec(data.clone()));
+		assert_eq!(ProportionalAssetToMainOwner::<Test>::get(id_1), Some(1));
+		assert_eq!(ProportionalAssetToMainOwner::<Test>::get(id_2), Some(2));
+
+		// The nonce lets a creator reuse the same data as well
+		assert_eq!(CreatorNonce::<Test>::get(1), 1);
+		let id_3 = ProportionalAssetModule::next_asset_id(&1, &data);
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data,
+			1,
+			None,
+			None
+		));
+		assert_ne!(id_1, id_3);
+		assert_eq!(ProportionalAssetToMainOwner::<Test>::get(id_3), Some(1));
+	});
+}
+
+#[test]
+fn unsalted_asset_ids_collide_across_creators() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_eq!(
+			ProportionalAssetModule::next_asset_id(&1, &data),
+			get_hash_from_vec(data.clone())
+		);
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+
+		assert_noop!(
+			ProportionalAssetModule::create_proportional_asset(
+				Origin::signed(2),
+				data,
+				1,
+				None,
+				None
+			),
+			Error::<Test>::AssetAlreadyExists
+		);
+		assert_eq!(CreatorNonce::<Test>::get(1), 0);
+	});
+}
+
+#[test]
+fn try_state_holds_for_consistent_storage() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			30,
+			2
+		));
+		assert_ok!(ProportionalAssetModule::burn_shares(Origin::signed(2), id, 10));
+
+		assert_ok!(ProportionalAssetModule::do_try_state());
+	});
+}
+
+#[test]
+fn try_state_catches_minted_shares() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		ProportionalAssetToOwnerToMetadata::<Test>::mutate(id, 1, |metadata| {
+			metadata.as_mut().unwrap().shares = Shares(TotalSupply::get() + 1);
+		});
+
+		assert_eq!(
+			ProportionalAssetModule::do_try_state(),
+			Err("The shares of an asset do not add up to the total supply")
+		);
+	});
+}
+
+#[test]
+fn try_state_catches_main_owner_without_majority() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			40,
+			2
+		));
+		ProportionalAssetToMainOwner::<Test>::insert(id, 2);
+
+		assert_eq!(
+			ProportionalAssetModule::do_try_state(),
+			Err("The main owner of an asset does not hold a majority")
+		);
+	});
+}
+
+#[test]
+fn buy_private_works() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::offer_shares_to(Origin::signed(1), id, 10, 2, 2));
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::PrivateOfferMade {
+			id,
+			seller: 1,
+			buyer: 2,
+			shares: 10,
+			price: 2,
+		}));
+
+		assert_ok!(ProportionalAssetModule::buy_private(Origin::signed(2), id, 1));
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::SharesBought {
+			id,
+			buyer: 2,
+			seller: 1,
+			shares: 10,
+			price_paid: 20,
+		}));
+
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &1), 90);
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &2), 10);
+		assert_eq!(Balances::free_balance(1), 70);
+		assert_eq!(Balances::free_balance(2), 30);
+		assert_eq!(PrivateOffers::<Test>::get((id, 1), 2), None);
+	});
+}
+
+#[test]
+fn buy_private_failure_not_the_buyer() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::offer_shares_to(Origin::signed(1), id, 10, 2, 2));
+
+		assert_noop!(
+			ProportionalAssetModule::buy_private(Origin::signed(3), id, 1),
+			Error::<Test>::BuyerNotAllowed
+		);
+		assert_eq!(PrivateOffers::<Test>::get((id, 1), 2), Some((10, 2)));
+	});
+}
+
+#[test]
+fn offer_shares_to_cannot_commit_shares_twice() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::offer_shares_to(Origin::signed(1), id, 60, 1, 2));
+		assert_noop!(
+			ProportionalAssetModule::offer_shares_to(Origin::signed(1), id, 50, 1, 3),
+			Error::<Test>::OverCommittedOffers
+		);
+
+		// Replacing the offer to the same buyer does not count the replaced shares
+		assert_ok!(ProportionalAssetModule::offer_shares_to(Origin::signed(1), id, 100, 1, 2));
+
+		assert_ok!(ProportionalAssetModule::offer_shares_to(Origin::signed(1), id, 0, 1, 2));
+		assert_eq!(PrivateOffers::<Test>::get((id, 1), 2), None);
+	});
+}
+
+#[test]
+fn committed_offers_sums_all_channels() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 30, 1, None));
+		assert_ok!(ProportionalAssetModule::place_order(Origin::signed(1), id, 20, 1));
+		assert_ok!(ProportionalAssetModule::offer_shares_to(Origin::signed(1), id, 10, 1, 2));
+
+		assert_eq!(ProportionalAssetModule::committed_offers(&id, &1), 60);
+	});
+}
+
+#[test]
+fn offer_shares_failure_over_committed_with_private_offer() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::offer_shares_to(Origin::signed(1), id, 70, 1, 2));
+		assert_noop!(
+			ProportionalAssetModule::offer_shares(Origin::signed(1), id, 40, 1, None),
+			Error::<Test>::OverCommittedOffers
+		);
+
+		// Replacing the public offer only counts the new amount
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 30, 1, None));
+		assert_noop!(
+			ProportionalAssetModule::offer_shares_to(Origin::signed(1), id, 1, 1, 3),
+			Error::<Test>::OverCommittedOffers
+		);
+
+		// An increased offer is clamped to the shares that are not privately offered
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 20, 1, None));
+		assert_ok!(ProportionalAssetModule::increase_offer(Origin::signed(1), id, 50));
+		let metadata = ProportionalAssetToOwnerToMetadata::<Test>::get(id, 1).unwrap();
+		assert_eq!(metadata.offers, Shares(30));
+	});
+}
+
+#[test]
+fn metadata_or_default_does_not_create_an_owner() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_eq!(ProportionalAssetModule::metadata_or_default(&id, &2), Default::default());
+		assert!(!ProportionalAssetToOwnerToMetadata::<Test>::contains_key(id, 2));
+		assert_eq!(ProportionalAssetModule::owner_count(&id), 1);
+
+		// The default holding does not pass as an ownership
+		assert_noop!(
+			ProportionalAssetModule::offer_shares(Origin::signed(2), id, 1, 1, None),
+			Error::<Test>::NotAnOwner
+		);
+
+		// A first purchase starts from the default holding
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 10, 1, None));
+		assert_ok!(ProportionalAssetModule::buy_shares(Origin::signed(2), id, 10, 10, 1, 10));
+		let metadata = ProportionalAssetModule::metadata_or_default(&id, &2);
+		assert_eq!(metadata.shares, Shares(10));
+		assert_eq!(metadata.offers, Shares(0));
+		assert_eq!(metadata.price, 0);
+	});
+}
+
+#[test]
+fn creation_deposit_reservation_and_refund_emit_events() {
+	new_test_ext().execute_with(|| {
+		AssetDeposit::set(10);
+
+		let data = get_test_data();
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			10,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+		System::assert_has_event(Event::ProportionalAssetModule(crate::Event::DepositReserved(
+			id, 1, 10,
+		)));
+
+		assert_ok!(ProportionalAssetModule::destroy_asset(Origin::signed(1), id));
+		System::assert_has_event(Event::ProportionalAssetModule(crate::Event::DepositRefunded(
+			id, 1, 10,
+		)));
+	})
+}
+
+#[test]
+fn creation_deposit_events_skipped_without_deposit() {
+	new_test_ext().execute_with(|| {
+		let data = get_test_data();
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			10,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+		assert_ok!(ProportionalAssetModule::destroy_asset(Origin::signed(1), id));
+
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			Event::ProportionalAssetModule(
+				crate::Event::DepositReserved(..) | crate::Event::DepositRefunded(..)
+			)
+		)));
+	})
+}
+
+#[test]
+fn transfer_all_shares_works() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			30,
+			2
+		));
+
+		assert_ok!(ProportionalAssetModule::transfer_all_shares(Origin::signed(2), id, 3));
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::SharesTransferred(
+			id, 2, 3, 30,
+		)));
+
+		assert!(!ProportionalAssetToOwnerToMetadata::<Test>::contains_key(id, 2));
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &3), 30);
+
+		assert_noop!(
+			ProportionalAssetModule::transfer_all_shares(Origin::signed(2), id, 3),
+			Error::<Test>::NotAnOwner
+		);
+	});
+}
+
+#[test]
+fn transfer_all_shares_keeps_locked_shares() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::lock_shares(Origin::signed(1), id, 60, 5));
+		assert_ok!(ProportionalAssetModule::transfer_all_shares(Origin::signed(1), id, 2));
+
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &1), 60);
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &2), 40);
+	});
+}
+
+#[test]
+fn distribute_proportionally_follows_rounding_policy() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		// 50/25/25 split between accounts 1, 2 and 3
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			25,
+			2
+		));
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			25,
+			3
+		));
+
+		// 1, 0.5 and 0.5 for a total of 2, then 3, 1.5 and 1.5 for a total of 6
+		for (rounding, cuts_of_2, cuts_of_6) in [
+			(Rounding::LargestRemainder, [1, 1, 0], [3, 2, 1]),
+			(Rounding::Down, [1, 0, 0], [3, 1, 1]),
+			(Rounding::Up, [1, 1, 1], [3, 2, 2]),
+			(Rounding::HalfEven, [1, 0, 0], [3, 2, 2]),
+		] {
+			RoundingPolicy::set(rounding);
+			assert_eq!(
+				ProportionalAssetModule::distribute_proportionally(&id, 2),
+				vec![(1, cuts_of_2[0]), (2, cuts_of_2[1]), (3, cuts_of_2[2])]
+			);
+			assert_eq!(
+				ProportionalAssetModule::distribute_proportionally(&id, 6),
+				vec![(1, cuts_of_6[0]), (2, cuts_of_6[1]), (3, cuts_of_6[2])]
+			);
+		}
+	});
+}
+
+#[test]
+fn split_asset_follows_rounding_policy() {
+	// 35% and 25% of a price of 10 are 3.5 and 2.5
+	for (rounding, price_35, price_25) in [
+		(Rounding::LargestRemainder, 3, 2),
+		(Rounding::Down, 3, 2),
+		(Rounding::Up, 4, 3),
+		(Rounding::HalfEven, 4, 2),
+	] {
+		new_test_ext().execute_with(|| {
+			RoundingPolicy::set(rounding);
+
+			for (data, percent, expected_price) in
+				[(vec![1, 2, 3], 35, price_35), (vec![4, 5, 6], 25, price_25)]
+			{
+				assert_ok!(ProportionalAssetModule::create_proportional_asset(
+					Origin::signed(1),
+					data.clone(),
+					10,
+					None,
+					None
+				));
+				let id = get_hash_from_vec(data.clone());
+
+				let data_a: Vec<u8> = [data.clone(), vec![0]].concat();
+				let data_b: Vec<u8> = [data, vec![1]].concat();
+				assert_ok!(ProportionalAssetModule::split_asset(
+					Origin::signed(1),
+					id,
+					data_a.clone(),
+					data_b.clone(),
+					Permill::from_percent(percent)
+				));
+
+				let price_a =
+					ProportionalAssetToOwnerToMetadata::<Test>::get(get_hash_from_vec(data_a), 1)
+						.unwrap()
+						.price;
+				let price_b =
+					ProportionalAssetToOwnerToMetadata::<Test>::get(get_hash_from_vec(data_b), 1)
+						.unwrap()
+						.price;
+				assert_eq!(price_a, expected_price);
+				assert_eq!(price_b, 10 - expected_price);
+			}
+		});
+	}
+}
+
+#[test]
+fn can_claim_ownership_requires_a_majority() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		let id = get_hash_from_vec(data.clone());
+		assert!(!ProportionalAssetModule::can_claim_ownership(&id, &1));
+
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data,
+			1,
+			None,
+			None
+		));
+		assert!(!ProportionalAssetModule::can_claim_ownership(&id, &1));
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			49,
+			2
+		));
+		assert!(!ProportionalAssetModule::can_claim_ownership(&id, &2));
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			1,
+			2
+		));
+		assert!(!ProportionalAssetModule::can_claim_ownership(&id, &2));
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			1,
+			2
+		));
+		assert!(ProportionalAssetModule::can_claim_ownership(&id, &2));
+
+		assert_ok!(ProportionalAssetModule::claim_onwership(Origin::signed(2), id));
+		assert!(!ProportionalAssetModule::can_claim_ownership(&id, &2));
+	});
+}
+
+#[test]
+fn buy_shares_updates_holdings_before_paying_the_seller() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		// Account 4 has no balance, the payment creates it and calls back into the pallet
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			20,
+			4
+		));
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(4), id, 10, 2, None));
+		ObservedAsset::set(Some(id));
+
+		assert_ok!(ProportionalAssetModule::buy_shares(Origin::signed(3), id, 10, 20, 4, 20));
+
+		assert_eq!(ObservedShares::get(), vec![(4, 10)]);
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &4), 10);
+		assert_eq!(Balances::free_balance(4), 20);
+	});
+}
+
+#[test]
+fn schedule_pause_blocks_trades_until_the_block() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 10, 1, None));
+
+		assert_noop!(
+			ProportionalAssetModule::schedule_pause(Origin::signed(2), id, 5),
+			Error::<Test>::NotMainOwner
+		);
+		assert_ok!(ProportionalAssetModule::schedule_pause(Origin::signed(1), id, 5));
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::TradingPaused(
+			id, 5,
+		)));
+
+		System::set_block_number(4);
+		assert_noop!(
+			ProportionalAssetModule::buy_shares(Origin::signed(2), id, 5, 5, 1, 5),
+			free_rejection(Error::<Test>::AssetPaused)
+		);
+		assert_eq!(
+			ProportionalAssetModule::quote_buy(&id, &2, &1, 5),
+			Err(Error::<Test>::AssetPaused)
+		);
+
+		// Transfers are not trades
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			5,
+			3
+		));
+
+		System::set_block_number(5);
+		assert_ok!(ProportionalAssetModule::buy_shares(Origin::signed(2), id, 5, 5, 1, 5));
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &2), 5);
+	});
+}
+
+#[test]
+fn schedule_pause_in_the_past_lifts_the_pause() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 10, 1, None));
+
+		assert_ok!(ProportionalAssetModule::schedule_pause(Origin::signed(1), id, 10));
+		assert!(ProportionalAssetModule::is_paused(&id));
+
+		assert_ok!(ProportionalAssetModule::schedule_pause(Origin::signed(1), id, 1));
+		assert!(!ProportionalAssetModule::is_paused(&id));
+		assert_ok!(ProportionalAssetModule::buy_shares(Origin::signed(2), id, 5, 5, 1, 5));
+	});
+}
+
+#[test]
+fn main_owned_assets_follows_the_main_owner() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+		assert_eq!(ProportionalAssetModule::main_owned_assets(&1), vec![id]);
+
+		// The majority moves to 2, who claims the asset
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			60,
+			2
+		));
+		assert!(ProportionalAssetModule::main_owned_assets(&1).is_empty());
+		assert_ok!(ProportionalAssetModule::claim_onwership(Origin::signed(2), id));
+		assert_eq!(ProportionalAssetModule::main_owned_assets(&2), vec![id]);
+
+		// The majority moves back to 1 while 2 keeps a main owner record
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(2),
+			id,
+			51,
+			1
+		));
+		ProportionalAssetModule::set_main_owner(2, &id);
+		assert_eq!(ProportionalAssetModule::main_owned_assets(&2), vec![id]);
+		assert_ok!(ProportionalAssetModule::transfer_main_ownership(Origin::signed(2), id, 1));
+		assert!(ProportionalAssetModule::main_owned_assets(&2).is_empty());
+		assert_eq!(ProportionalAssetModule::main_owned_assets(&1), vec![id]);
+		assert_ok!(ProportionalAssetModule::do_try_state());
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(2),
+			id,
+			9,
+			1
+		));
+		assert_ok!(ProportionalAssetModule::destroy_asset(Origin::signed(1), id));
+		assert!(!MainOwnerToAssets::<Test>::contains_key(1, id));
+		assert!(ProportionalAssetModule::main_owned_assets(&1).is_empty());
+	});
+}
+
+#[test]
+fn display_ownership_round_trips() {
+	for (decimals, supply, shares) in [(2, 10_000, 1_234), (6, 100_000_000, 12_345_678)] {
+		new_test_ext().execute_with(|| {
+			// The 100 shares of an asset are stored in units of the decimals
+			ShareDecimals::set(decimals);
+			assert_eq!(ProportionalAssetModule::total_units(), supply);
+
+			let data: Vec<u8> = vec![1, 2, 3];
+			assert_ok!(ProportionalAssetModule::create_proportional_asset(
+				Origin::signed(1),
+				data.clone(),
+				1,
+				None,
+				None
+			));
+			let id = get_hash_from_vec(data);
+			assert_eq!(ProportionalAssetModule::shares_of(&id, &1), supply);
+
+			// A fraction of a share moves as units
+			assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+				Origin::signed(1),
+				id,
+				shares,
+				2
+			));
+
+			// 12.34% and 12.345678% are displayed without losing a digit
+			let display = ProportionalAssetModule::display_ownership(&id, &2);
+			assert_eq!(display, shares as u128);
+			assert_eq!(ProportionalAssetModule::display_to_shares(display, supply), shares);
+			assert_eq!(
+				ProportionalAssetModule::display_ownership(&id, &1),
+				100 * 10u128.pow(decimals) - display
+			);
+		});
+	}
+}
+
+#[test]
+fn display_to_shares_rounds_down_below_a_share() {
+	new_test_ext().execute_with(|| {
+		ShareDecimals::set(2);
+
+		// 12.34% of 100 shares is 12.34 shares
+		assert_eq!(ProportionalAssetModule::display_to_shares(1_234, 100), 12);
+		assert_eq!(ProportionalAssetModule::shares_to_display(12, 100), 1_200);
+		assert_eq!(ProportionalAssetModule::display_to_shares(u128::MAX, 100), 100);
+		assert_eq!(ProportionalAssetModule::shares_to_display(1, 0), 0);
+	});
+}
+
+#[test]
+fn transfer_clamps_or_clears_offers() {
+	for (clear, expected_offers) in [(false, 30), (true, 0)] {
+		new_test_ext().execute_with(|| {
+			ClearOffersOnTransfer::set(clear);
+
+			let data: Vec<u8> = vec![1, 2, 3];
+			assert_ok!(ProportionalAssetModule::create_proportional_asset(
+				Origin::signed(1),
+				data.clone(),
+				1,
+				None,
+				None
+			));
+			let id = get_hash_from_vec(data);
+			assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 50, 1, None));
+
+			assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+				Origin::signed(1),
+				id,
+				70,
+				2
+			));
+
+			let metadata = ProportionalAssetToOwnerToMetadata::<Test>::get(id, 1).unwrap();
+			assert_eq!(metadata.shares, Shares(30));
+			assert_eq!(metadata.offers, Shares(expected_offers));
+		});
+	}
+}
+
+#[test]
+fn transfer_batch_clamps_or_clears_offers() {
+	for (clear, expected_offers) in [(false, 30), (true, 0)] {
+		new_test_ext().execute_with(|| {
+			ClearOffersOnTransfer::set(clear);
+
+			let data: Vec<u8> = vec![1, 2, 3];
+			assert_ok!(ProportionalAssetModule::create_proportional_asset(
+				Origin::signed(1),
+				data.clone(),
+				1,
+				None,
+				None
+			));
+			let id = get_hash_from_vec(data);
+			assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 50, 1, None));
+
+			assert_ok!(ProportionalAssetModule::transfer_shares_batch(
+				Origin::signed(1),
+				id,
+				vec![(2, 40), (3, 30)]
+			));
+
+			let metadata = ProportionalAssetToOwnerToMetadata::<Test>::get(id, 1).unwrap();
+			assert_eq!(metadata.shares, Shares(30));
+			assert_eq!(metadata.offers, Shares(expected_offers));
+		});
+	}
+}
+
+#[test]
+fn offer_shares_respects_min_reoffer_interval() {
+	new_test_ext().execute_with(|| {
+		MinReofferInterval::set(5);
+
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 10, 1, None));
+
+		System::set_block_number(5);
+		assert_noop!(
+			ProportionalAssetModule::offer_shares(Origin::signed(1), id, 10, 2, None),
+			Error::<Test>::ReofferTooSoon
+		);
+
+		System::set_block_number(6);
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 10, 2, None));
+		assert_eq!(ProportionalAssetToOwnerToMetadata::<Test>::get(id, 1).unwrap().price, 2);
+	});
+}
+
+#[test]
+fn auction_settles_to_the_highest_bidder() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_noop!(
+			ProportionalAssetModule::start_auction(Origin::signed(1), id, 10, 20, 1),
+			Error::<Test>::AuctionEnded
+		);
+		let seller_balance = Balances::free_balance(1);
+		assert_ok!(ProportionalAssetModule::start_auction(Origin::signed(1), id, 10, 20, 5));
+		assert_noop!(
+			ProportionalAssetModule::start_auction(Origin::signed(1), id, 10, 20, 6),
+			Error::<Test>::AuctionExists
+		);
+		assert_eq!(ProportionalAssetModule::auctioned_shares(&id, &1), 10);
+
+		assert_noop!(
+			ProportionalAssetModule::place_auction_bid(Origin::signed(1), id, 30),
+			Error::<Test>::IncorrectSeller
+		);
+		assert_noop!(
+			ProportionalAssetModule::place_auction_bid(Origin::signed(2), id, 10),
+			Error::<Test>::AuctionBidTooLow
+		);
+		assert_ok!(ProportionalAssetModule::place_auction_bid(Origin::signed(2), id, 20));
+		// Raising the bid to 60 reserves 40 more than the 30 left free
+		assert_noop!(
+			ProportionalAssetModule::place_auction_bid(Origin::signed(2), id, 60),
+			Error::<Test>::InsufficientBalance
+		);
+		assert_noop!(
+			ProportionalAssetModule::place_auction_bid(Origin::signed(3), id, 20),
+			Error::<Test>::AuctionBidTooLow
+		);
+		assert_ok!(ProportionalAssetModule::place_auction_bid(Origin::signed(3), id, 25));
+		// Raising a bid only reserves the difference
+		assert_ok!(ProportionalAssetModule::place_auction_bid(Origin::signed(2), id, 30));
+		assert_eq!(Balances::reserved_balance(2), 30);
+		assert_ok!(ProportionalAssetModule::place_auction_bid(Origin::signed(3), id, 40));
+		assert_eq!(Balances::reserved_balance(3), 40);
+
+		System::set_block_number(5);
+		assert_noop!(
+			ProportionalAssetModule::place_auction_bid(Origin::signed(2), id, 50),
+			Error::<Test>::AuctionEnded
+		);
+		ProportionalAssetModule::on_initialize(5);
+
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::AuctionSettled {
+			id,
+			seller: 1,
+			winner: Some(3),
+			shares: 10,
+			price: 40,
+		}));
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &3), 10);
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &1), 90);
+		assert_eq!(Balances::free_balance(1), seller_balance + 40);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 50);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::free_balance(3), 960);
+		assert_eq!(Auctions::<Test>::get(id), None);
+		assert_eq!(AuctionBids::<Test>::iter_prefix(id).count(), 0);
+	});
+}
+
+#[test]
+fn auction_without_bids_releases_the_shares() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::start_auction(Origin::signed(1), id, 100, 20, 5));
+		// The auctioned shares cannot be offered anymore
+		assert_noop!(
+			ProportionalAssetModule::offer_shares(Origin::signed(1), id, 1, 1, None),
+			Error::<Test>::OverCommittedOffers
+		);
+
+		System::set_block_number(5);
+		ProportionalAssetModule::on_initialize(5);
+
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::AuctionSettled {
+			id,
+			seller: 1,
+			winner: None,
+			shares: 100,
+			price: 0,
+		}));
+		assert_eq!(ProportionalAssetModule::auctioned_shares(&id, &1), 0);
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 1, 1, None));
+	});
+}
+
+// The error of a call rejected without charging the caller.
+fn free_rejection(error: Error<Test>) -> DispatchErrorWithPostInfo {
+	DispatchErrorWithPostInfo { post_info: Pays::No.into(), error: error.into() }
+}
+
+#[test]
+fn buying_from_a_frozen_asset_is_free() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 10, 1, None));
+		assert_ok!(ProportionalAssetModule::set_frozen(Origin::signed(1), id, true));
+
+		let rejection =
+			ProportionalAssetModule::buy_shares(Origin::signed(2), id, 5, 5, 1, 5).unwrap_err();
+		assert_eq!(rejection.post_info.pays_fee, Pays::No);
+		assert_eq!(rejection.error, Error::<Test>::AssetFrozen.into());
+
+		// Other failures and successful purchases are still charged
+		assert_ok!(ProportionalAssetModule::set_frozen(Origin::signed(1), id, false));
+		let rejection =
+			ProportionalAssetModule::buy_shares(Origin::signed(2), id, 20, 20, 1, 20).unwrap_err();
+		assert_eq!(rejection.post_info.pays_fee, Pays::Yes);
+		let post_info =
+			ProportionalAssetModule::buy_shares(Origin::signed(2), id, 5, 5, 1, 5).unwrap();
+		assert_eq!(post_info.pays_fee, Pays::Yes);
+	});
+}
+
+#[test]
+fn offer_shares_rejects_price_above_max() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_noop!(
+			ProportionalAssetModule::offer_shares(
+				Origin::signed(1),
+				id,
+				10,
+				MaxSharePrice::get() + 1,
+				None
+			),
+			Error::<Test>::PriceTooHigh
+		);
+		assert_noop!(
+			ProportionalAssetModule::place_order(
+				Origin::signed(1),
+				id,
+				10,
+				MaxSharePrice::get() + 1
+			),
+			Error::<Test>::PriceTooHigh
+		);
+
+		// The whole supply at the maximum price can still be quoted
+		let total_price = MaxSharePrice::get() * TotalSupply::get() as Balance;
+		assert_ok!(Balances::set_balance(Origin::root(), 2, total_price, 0));
+		assert_ok!(ProportionalAssetModule::offer_shares(
+			Origin::signed(1),
+			id,
+			TotalSupply::get(),
+			MaxSharePrice::get(),
+			None
+		));
+		assert_eq!(
+			ProportionalAssetModule::quote_buy(&id, &2, &1, TotalSupply::get()),
+			Ok(total_price)
+		);
+	});
+}
+
+#[test]
+fn reconcile_main_owner_vacates_a_stale_main_owner() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		// A main owner with a majority is left in place
+		assert_ok!(ProportionalAssetModule::reconcile_main_owner(Origin::signed(3), id));
+		assert_eq!(ProportionalAssetToMainOwner::<Test>::get(id), Some(1));
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			70,
+			2
+		));
+		ProportionalAssetModule::set_main_owner(1, &id);
+
+		// Anyone can reconcile, the majority holder is not made the main owner
+		assert_ok!(ProportionalAssetModule::reconcile_main_owner(Origin::signed(3), id));
+		assert_eq!(ProportionalAssetToMainOwner::<Test>::get(id), None);
+		assert_eq!(ProportionalAssetModule::main_owned_assets(&1), vec![]);
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::MainOwnerVacated(
+			id,
+		)));
+
+		// The majority holder takes the role through a claim
+		assert_ok!(ProportionalAssetModule::claim_onwership(Origin::signed(2), id));
+		assert_eq!(ProportionalAssetToMainOwner::<Test>::get(id), Some(2));
+	});
+}
+
+#[test]
+fn reconcile_main_owner_reserves_nothing() {
+	new_test_ext().execute_with(|| {
+		AssetDeposit::set(10);
+
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		// The majority moves to an account without any free balance
+		assert_eq!(Balances::free_balance(4), 0);
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			70,
+			4
+		));
+		ProportionalAssetModule::set_main_owner(1, &id);
+
+		// A third party with nothing reserved reconciles the asset
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_ok!(ProportionalAssetModule::reconcile_main_owner(Origin::signed(3), id));
+		assert_eq!(ProportionalAssetToMainOwner::<Test>::get(id), None);
+
+		// The deposit stays with the account that reserved it
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert_eq!(ProportionalAssetToDeposit::<Test>::get(id), Some((1, 10)));
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::reserved_balance(4), 0);
+		assert_eq!(Balances::free_balance(3), 1_000);
+
+		// The majority holder cannot take the role without covering the deposit
+		assert_noop!(
+			ProportionalAssetModule::claim_onwership(Origin::signed(4), id),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn buy_shares_fails_with_typed_error_on_currency_transfer_failure() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		// The seller has no balance, so a payment below the existential deposit is rejected
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			10,
+			4
+		));
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(4), id, 10, 1, None));
+		ExistentialDeposit::set(5);
+
+		assert_noop!(
+			ProportionalAssetModule::buy_shares(Origin::signed(3), id, 2, 2, 4, 2),
+			Error::<Test>::CurrencyTransferFailed
+		);
+	});
+}
+
+#[test]
+fn offer_shares_records_the_price_history() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+		assert_eq!(ProportionalAssetModule::price_history(&id), vec![]);
+
+		for (block, price) in [(1, 10), (2, 11), (3, 12)] {
+			System::set_block_number(block);
+			assert_ok!(ProportionalAssetModule::offer_shares(
+				Origin::signed(1),
+				id,
+				10,
+				price,
+				None
+			));
+		}
+		assert_eq!(ProportionalAssetModule::price_history(&id), vec![(1, 10), (2, 11), (3, 12)]);
+
+		// The oldest price is evicted once the history is full
+		System::set_block_number(4);
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 10, 13, None));
+		assert_eq!(ProportionalAssetModule::price_history(&id), vec![(2, 11), (3, 12), (4, 13)]);
+	});
+}
+
+#[test]
+fn force_transfer_shares_works_for_root_only() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_noop!(
+			ProportionalAssetModule::force_transfer_shares(Origin::signed(2), id, 1, 2, 60),
+			BadOrigin
+		);
+
+		assert_ok!(ProportionalAssetModule::force_transfer_shares(Origin::root(), id, 1, 2, 60));
+		System::assert_last_event(Event::ProportionalAssetModule(
+			crate::Event::SharesForceTransferred { id, from: 1, to: 2, amount: 60 },
+		));
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &1), 40);
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &2), 60);
+		// The former main owner lost the majority
+		assert_eq!(ProportionalAssetToMainOwner::<Test>::get(id), None);
+
+		assert_noop!(
+			ProportionalAssetModule::force_transfer_shares(Origin::root(), id, 3, 2, 1),
+			Error::<Test>::InvalidAccount
+		);
+	});
+}
+
+#[test]
+fn transfer_fails_on_recipient_share_overflow() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		// A broken state where the recipient holds far more than the supply
+		ProportionalAssetToOwnerToMetadata::<Test>::insert(
+			id,
+			2,
+			crate::MetaData { shares: Shares(u64::MAX - 5), offers: Shares(0), price: 1 },
+		);
+
+		assert_noop!(
+			ProportionalAssetModule::transfer_shares_to_account(Origin::signed(1), id, 10, 2),
+			Error::<Test>::ShareOverflow
+		);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 10, 1, None));
+		assert_noop!(
+			ProportionalAssetModule::buy_shares(Origin::signed(2), id, 10, 10, 1, 10),
+			Error::<Test>::ShareOverflow
+		);
+	});
+}
+
+#[test]
+fn core_calls_emit_deterministic_events() {
+	new_test_ext().execute_with(|| {
+		let pallet_events = || {
+			let events = System::events()
+				.into_iter()
+				.filter_map(|record| match record.event {
+					Event::ProportionalAssetModule(event) => Some(event),
+					_ => None,
+				})
+				.collect::<Vec<_>>();
+			System::reset_events();
+			events
+		};
+
+		let data = get_test_data();
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			10,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+		assert_eq!(
+			pallet_events(),
+			vec![crate::Event::ProportionalAssetInitialized(id, 1, 100, 10)]
+		);
+
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 20, 2, None));
+		assert_eq!(
+			pallet_events(),
+			vec![crate::Event::SharesOffered { id, seller: 1, shares: 20, price: 2 }]
+		);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			40,
+			2
+		));
+		assert_eq!(pallet_events(), vec![crate::Event::SharesTransferred(id, 1, 2, 40)]);
+
+		assert_ok!(ProportionalAssetModule::buy_shares(Origin::signed(3), id, 20, 40, 1, 40));
+		assert_eq!(
+			pallet_events(),
+			vec![
+				crate::Event::SharesBought { id, buyer: 3, seller: 1, shares: 20, price_paid: 40 },
+				crate::Event::TradeSettled {
+					id,
+					buyer: 3,
+					seller: 1,
+					shares: 20,
+					price: 2,
+					buyer_shares_after: 20,
+					seller_shares_after: 40,
+				},
+				// The main owner is left without a majority
+				crate::Event::MainOwnerVacated(id),
+			]
+		);
+
+		// Once holding a majority the slot can be claimed
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(3),
+			id,
+			20,
+			2
+		));
+		pallet_events();
+		assert_ok!(ProportionalAssetModule::claim_onwership(Origin::signed(2), id));
+		assert_eq!(pallet_events(), vec![crate::Event::MainOwnerSet(2, id)]);
+	});
+}
+
+#[test]
+fn quote_buy_rejects_what_buy_shares_rejects() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 10, 1, Some(5)));
+		assert_ok!(ProportionalAssetModule::set_offer_allowlist(Origin::signed(1), id, vec![2]));
+
+		assert_eq!(ProportionalAssetModule::quote_buy(&id, &2, &1, 10), Ok(10));
+		assert_eq!(
+			ProportionalAssetModule::quote_buy(&id, &3, &1, 10),
+			Err(Error::<Test>::BuyerNotAllowed)
+		);
+
+		System::set_block_number(6);
+		assert_eq!(
+			ProportionalAssetModule::quote_buy(&id, &2, &1, 10),
+			Err(Error::<Test>::OfferExpired)
+		);
+
+		assert_ok!(ProportionalAssetModule::set_frozen(Origin::signed(1), id, true));
+		assert_eq!(
+			ProportionalAssetModule::quote_buy(&id, &2, &1, 10),
+			Err(Error::<Test>::AssetFrozen)
+		);
+	});
+}
+
+#[test]
+fn increase_offer_applies_the_offer_guards() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ProportionalAssetModule::create_proportional_asset(
+				Origin::signed(1),
+				vec![1, 2, 3],
+				MaxSharePrice::get() + 1,
+				None,
+				None
+			),
+			Error::<Test>::PriceTooHigh
+		);
+
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			10,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 10, 10, None));
+
+		MinReofferInterval::set(5);
+		System::set_block_number(3);
+		assert_noop!(
+			ProportionalAssetModule::increase_offer(Origin::signed(1), id, 10),
+			Error::<Test>::ReofferTooSoon
+		);
+
+		System::set_block_number(6);
+		MaxSharePrice::set(5);
+		assert_noop!(
+			ProportionalAssetModule::increase_offer(Origin::signed(1), id, 10),
+			Error::<Test>::PriceTooHigh
+		);
+
+		MaxSharePrice::set(10);
+		assert_ok!(ProportionalAssetModule::increase_offer(Origin::signed(1), id, 10));
+		assert_eq!(ProportionalAssetModule::price_history(&id), vec![(1, 10), (6, 10)]);
+	});
+}
+
+#[test]
+fn auction_settlements_carry_over_past_the_cap() {
+	new_test_ext().execute_with(|| {
+		MaxAuctionSettlements::set(1);
+
+		let first: Vec<u8> = vec![1, 2, 3];
+		let second: Vec<u8> = vec![4, 5, 6];
+		for data in [first.clone(), second.clone()] {
+			assert_ok!(ProportionalAssetModule::create_proportional_asset(
+				Origin::signed(1),
+				data.clone(),
+				1,
+				None,
+				None
+			));
+			let id = get_hash_from_vec(data);
+			assert_ok!(ProportionalAssetModule::start_auction(Origin::signed(1), id, 10, 20, 5));
+			assert_ok!(ProportionalAssetModule::place_auction_bid(Origin::signed(3), id, 20));
+		}
+
+		System::set_block_number(5);
+		ProportionalAssetModule::on_initialize(5);
+		assert_eq!(Auctions::<Test>::iter().count(), 1);
+		assert_eq!(NextAuctionSettlement::<Test>::get(), Some(5));
+
+		// The auction left over is settled in the next block
+		System::set_block_number(6);
+		ProportionalAssetModule::on_initialize(6);
+		assert_eq!(Auctions::<Test>::iter().count(), 0);
+		for data in [first, second] {
+			assert_eq!(ProportionalAssetModule::shares_of(&get_hash_from_vec(data), &3), 10);
+		}
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::free_balance(3), 1_000 - 40);
+
+		// Moving past a block without auctions uses up the budget as well
+		System::set_block_number(7);
+		ProportionalAssetModule::on_initialize(7);
+		assert_eq!(NextAuctionSettlement::<Test>::get(), Some(6));
+	});
+}
+
+#[test]
+fn auction_of_a_frozen_asset_refunds_every_bidder() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_noop!(
+			ProportionalAssetModule::place_auction_bid(
+				Origin::signed(3),
+				get_hash_from_vec(vec![4, 5, 6]),
+				20
+			),
+			Error::<Test>::AssetDoesNotExist
+		);
+
+		assert_ok!(ProportionalAssetModule::start_auction(Origin::signed(1), id, 10, 20, 5));
+		assert_ok!(ProportionalAssetModule::place_auction_bid(Origin::signed(2), id, 20));
+		assert_ok!(ProportionalAssetModule::place_auction_bid(Origin::signed(3), id, 30));
+		assert_ok!(ProportionalAssetModule::set_frozen(Origin::signed(1), id, true));
+		let seller_balance = Balances::free_balance(1);
+
+		System::set_block_number(5);
+		ProportionalAssetModule::on_initialize(5);
+
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::AuctionSettled {
+			id,
+			seller: 1,
+			winner: None,
+			shares: 10,
+			price: 0,
+		}));
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &1), 100);
+		assert_eq!(Balances::free_balance(1), seller_balance);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::free_balance(3), 1_000);
+	});
+}
+
+#[test]
+fn auction_settlement_charges_marketplace_fee() {
+	new_test_ext().execute_with(|| {
+		MarketplaceFee::set(Permill::from_percent(10));
+
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::start_auction(Origin::signed(1), id, 10, 20, 5));
+		assert_ok!(ProportionalAssetModule::place_auction_bid(Origin::signed(3), id, 50));
+		let seller_balance = Balances::free_balance(1);
+
+		System::set_block_number(5);
+		ProportionalAssetModule::on_initialize(5);
+
+		System::assert_has_event(Event::ProportionalAssetModule(crate::Event::FeeCharged(id, 5)));
+		assert_eq!(Balances::free_balance(FeeCollector::get()), 5);
+		assert_eq!(Balances::free_balance(1), seller_balance + 45);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::free_balance(3), 1_000 - 50);
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &3), 10);
+	});
+}
+
+#[test]
+fn fungibles_deposits_follow_the_holding_limits() {
+	use frame_support::traits::tokens::{fungibles::Inspect, DepositConsequence};
+
+	new_test_ext().execute_with(|| {
+		MinHolding::set(5);
+		MaxHolders::set(2);
+
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_eq!(<ProportionalAssetModule as Inspect<u64>>::minimum_balance(id), 5);
+		assert_eq!(
+			<ProportionalAssetModule as Inspect<u64>>::can_deposit(id, &2, 4, false),
+			DepositConsequence::BelowMinimum
+		);
+		assert_eq!(
+			<ProportionalAssetModule as Inspect<u64>>::can_deposit(id, &2, 5, false),
+			DepositConsequence::Success
+		);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			10,
+			2
+		));
+		assert_eq!(
+			<ProportionalAssetModule as Inspect<u64>>::can_deposit(id, &3, 5, false),
+			DepositConsequence::CannotCreate
+		);
+		// Existing owners can still receive shares
+		assert_eq!(
+			<ProportionalAssetModule as Inspect<u64>>::can_deposit(id, &2, 1, false),
+			DepositConsequence::Success
+		);
+	});
+}
+
+#[test]
+fn force_transfer_shares_ignores_locks_and_freezes() {
+	new_test_ext().execute_with(|| {
+		MinShareTransfer::set(10);
+
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			30,
+			2
+		));
+
+		// The sender offers and locks the disputed shares, then the asset is frozen
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(2), id, 30, 1, None));
+		assert_ok!(ProportionalAssetModule::lock_shares(Origin::signed(2), id, 30, 10));
+		assert_ok!(ProportionalAssetModule::set_frozen(Origin::signed(1), id, true));
+		assert_noop!(
+			ProportionalAssetModule::transfer_shares_to_account(Origin::signed(2), id, 25, 3),
+			Error::<Test>::AssetFrozen
+		);
+
+		// Below the minimum transfer as well
+		assert_ok!(ProportionalAssetModule::force_transfer_shares(Origin::root(), id, 2, 3, 5));
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &2), 25);
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &3), 5);
+		assert_eq!(ProportionalAssetModule::locked_shares(&id, &2), 25);
+		assert_eq!(
+			ProportionalAssetToOwnerToMetadata::<Test>::get(id, 2).unwrap().offers,
+			Shares(25)
+		);
+
+		// Moving every share clears the lock of the sender
+		assert_ok!(ProportionalAssetModule::force_transfer_shares(Origin::root(), id, 2, 3, 25));
+		assert_eq!(ProportionalAssetModule::shares_of(&id, &3), 30);
+		assert_eq!(ProportionalAssetModule::locked_shares(&id, &2), 0);
+		assert!(!crate::ProportionalAssetToLocks::<Test>::contains_key(id, 2));
+	});
+}
+
+#[test]
+fn vote_rejecting_majority_closes_proposal() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			40,
+			2
+		));
+		assert_ok!(ProportionalAssetModule::create_proposal(
+			Origin::signed(2),
+			id,
+			H256::repeat_byte(1)
+		));
+		assert_eq!(OpenProposals::<Test>::get(id), 1);
+
+		assert_ok!(ProportionalAssetModule::vote(Origin::signed(1), id, 0, false));
+
+		System::assert_last_event(Event::ProportionalAssetModule(crate::Event::ProposalRejected(
+			id, 0,
+		)));
+		assert!(!Proposals::<Test>::contains_key(id, 0));
+		assert!(!ProposalTallies::<Test>::contains_key(id, 0));
+		assert!(!OpenProposals::<Test>::contains_key(id));
+	});
+}
+
+#[test]
+fn create_proposal_failure_too_many_open_proposals() {
+	new_test_ext().execute_with(|| {
+		MaxOpenProposals::set(2);
+
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		let id = get_hash_from_vec(data);
+
+		assert_ok!(ProportionalAssetModule::transfer_shares_to_account(
+			Origin::signed(1),
+			id,
+			40,
+			2
+		));
+		assert_ok!(ProportionalAssetModule::create_proposal(
+			Origin::signed(2),
+			id,
+			H256::repeat_byte(1)
+		));
+		assert_ok!(ProportionalAssetModule::create_proposal(
+			Origin::signed(2),
+			id,
+			H256::repeat_byte(2)
+		));
+		assert_noop!(
+			ProportionalAssetModule::create_proposal(Origin::signed(2), id, H256::repeat_byte(3)),
+			Error::<Test>::TooManyProposals
+		);
+
+		// Closing a proposal frees a slot
+		assert_ok!(ProportionalAssetModule::vote(Origin::signed(1), id, 0, true));
+		assert_ok!(ProportionalAssetModule::create_proposal(
+			Origin::signed(2),
+			id,
+			H256::repeat_byte(3)
+		));
+		assert_eq!(OpenProposals::<Test>::get(id), 2);
+	});
+}
+
+#[test]
+fn quote_buy_applies_the_holding_and_balance_checks() {
+	new_test_ext().execute_with(|| {
+		let id = get_hash_from_vec(vec![1, 2, 3]);
+		assert_eq!(
+			ProportionalAssetModule::quote_buy(&id, &2, &1, 10),
+			Err(Error::<Test>::AssetDoesNotExist)
+		);
+
+		let data: Vec<u8> = vec![1, 2, 3];
+		assert_ok!(ProportionalAssetModule::create_proportional_asset(
+			Origin::signed(1),
+			data.clone(),
+			1,
+			None,
+			None
+		));
+		assert_ok!(ProportionalAssetModule::offer_shares(Origin::signed(1), id, 100, 1, None));
+
+		// The buyer cannot pay for the shares
+		assert_eq!(
+			ProportionalAssetModule::quote_buy(&id, &4, &1, 10),
+			Err(Error::<Test>::InsufficientBalance)
+		);
+
+		MinShareTransfer::set(10);
+		assert_eq!(
+			ProportionalAssetModule::quote_buy(&id, &2, &1, 5),
+			Err(Error::<Test>::TransferBelowMinimum)
+		);
+
+		MinHolding::set(20);
+		assert_eq!(
+			ProportionalAssetModule::quote_buy(&id, &2, &1, 10),
+			Err(Error::<Test>::HoldingBelowMinimum)
+		);
+
+		MaxHoldingPercent::set(Permill::from_percent(30));
+		assert_eq!(
+			ProportionalAssetModule::quote_buy(&id, &3, &1, 40),
+			Err(Error::<Test>::HoldingCapExceeded)
+		);
+
+		MaxHolders::set(1);
+		assert_eq!(
+			ProportionalAssetModule::quote_buy(&id, &2, &1, 20),
+			Err(Error::<Test>::TooManyHolders)
+		);
+
+		// Each rejected quote is a rejected buy
+		assert_noop!(
+			ProportionalAssetModule::buy_shares(Origin::signed(2), id, 20, 20, 1, 20),
+			Error::<Test>::TooManyHolders
+		);
+	});
+}
+
+#[test]
+#[should_panic(expected = "The total supply in units should fit in u64")]
+fn integrity_test_rejects_too_many_decimals() {
+	new_test_ext().execute_with(|| {
+		ShareDecimals::set(18);
+		ProportionalAssetModule::integrity_test();
+	});
+}
@@ -0,0 +1,26 @@
+//! Traits exposed by the pallet to the other pallets of the runtime.
+
+/// Read-only access to the ownership of the proportional assets.
+pub trait ProportionalAssetInspect<AccountId, Hash> {
+	/// The main owner of an asset, if the asset has one.
+	fn main_owner(id: &Hash) -> Option<AccountId>;
+
+	/// The shares an account holds for an asset, 0 if the account is not an owner.
+	fn shares_of(id: &Hash, who: &AccountId) -> u64;
+
+	/// Whether an account holds any shares of an asset.
+	fn is_owner(id: &Hash, who: &AccountId) -> bool;
+
+	/// The number of accounts holding shares of an asset.
+	fn total_owners(id: &Hash) -> u32;
+}
+
+/// Handler notified whenever the main owner of a proportional asset changes.
+pub trait OnMainOwnerChanged<AccountId, Hash> {
+	/// The main owner of an asset changed from `old` to `new`.
+	fn on_changed(old: Option<AccountId>, new: AccountId, id: Hash);
+}
+
+impl<AccountId, Hash> OnMainOwnerChanged<AccountId, Hash> for () {
+	fn on_changed(_old: Option<AccountId>, _new: AccountId, _id: Hash) {}
+}
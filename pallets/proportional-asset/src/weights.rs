@@ -0,0 +1,875 @@
+//! Weights for pallet_proportional_asset
+//!
+//! The weights are estimated from the storage accessed by each call and should be regenerated
+//! from the benchmarks of the pallet with `scripts/benchmark.sh`, which runs:
+//!
+//! ./target/release/node-template benchmark pallet --chain dev --execution wasm
+//! --wasm-execution compiled --pallet pallet_proportional_asset --extrinsic '*' --steps 50
+//! --repeat 20 --output pallets/proportional-asset/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use core::marker::PhantomData;
+use frame_support::{traits::Get, weights::{constants::RocksDbWeight, Weight}};
+
+/// Weight functions needed for pallet_proportional_asset.
+pub trait WeightInfo {
+	fn create_proportional_asset() -> Weight;
+	fn offer_shares() -> Weight;
+	fn transfer_shares_to_account() -> Weight;
+	fn buy_shares() -> Weight;
+	fn claim_onwership() -> Weight;
+	fn burn_shares() -> Weight;
+	fn destroy_asset() -> Weight;
+	fn set_offer_allowlist(a: u32, ) -> Weight;
+	fn transfer_shares_batch(b: u32, ) -> Weight;
+	fn set_price_floor() -> Weight;
+	fn increase_offer() -> Weight;
+	fn distribute_dividend(o: u32, ) -> Weight;
+	fn transfer_main_ownership() -> Weight;
+	fn set_frozen() -> Weight;
+	fn place_order() -> Weight;
+	fn cancel_order() -> Weight;
+	fn fill_order() -> Weight;
+	fn lock_shares() -> Weight;
+	fn unlock_shares() -> Weight;
+	fn buyback_offered() -> Weight;
+	fn split_asset() -> Weight;
+	fn merge_assets() -> Weight;
+	fn create_proposal(o: u32, ) -> Weight;
+	fn vote(v: u32, ) -> Weight;
+	fn make_bid() -> Weight;
+	fn accept_bid() -> Weight;
+	fn cancel_bid() -> Weight;
+	fn set_status() -> Weight;
+	fn approve_shares() -> Weight;
+	fn transfer_shares_from() -> Weight;
+	fn force_destroy_asset(o: u32, ) -> Weight;
+	fn set_metadata_uri() -> Weight;
+	fn recover_main_owner() -> Weight;
+	fn register_swap_intent() -> Weight;
+	fn cancel_swap_intent() -> Weight;
+	fn swap_shares() -> Weight;
+	fn offer_shares_to() -> Weight;
+	fn buy_private() -> Weight;
+	fn transfer_all_shares() -> Weight;
+	fn schedule_pause() -> Weight;
+	fn reconcile_main_owner() -> Weight;
+	fn start_auction() -> Weight;
+	fn place_auction_bid() -> Weight;
+	fn settle_auction(b: u32, ) -> Weight;
+	fn force_transfer_shares() -> Weight;
+}
+
+/// Weights for pallet_proportional_asset using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToDeposit (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerCount (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToName (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerReverseIndex (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToUri (r:0 w:1)
+	// Storage: ProportionalAssetModule CreatorNonce (r:1 w:1)
+	// Storage: ProportionalAssetModule MainOwnerToAssets (r:0 w:1)
+	fn create_proportional_asset() -> Weight {
+		(28_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferExpiry (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToFrozen (r:1 w:0)
+	// Storage: ProportionalAssetModule OrderedShares (r:1 w:0)
+	// Storage: ProportionalAssetModule PrivateOffers (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToLastOffer (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToPriceHistory (r:1 w:1)
+	fn offer_shares() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:3 w:2)
+	// Storage: ProportionalAssetModule ProportionalAssetToBurnedShares (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferExpiry (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferAllowlist (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerCount (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToFrozen (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerReverseIndex (r:0 w:2)
+	fn transfer_shares_to_account() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:2 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:3 w:2)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferAllowlist (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferExpiry (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToBurnedShares (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerCount (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToFrozen (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerReverseIndex (r:0 w:2)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToStatus (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToTradeVolume (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToPauseUntil (r:1 w:0)
+	fn buy_shares() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(16 as Weight))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:2 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:2 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToBurnedShares (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToDeposit (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: ProportionalAssetModule MainOwnerToAssets (r:0 w:2)
+	fn claim_onwership() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:2 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToBurnedShares (r:2 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferExpiry (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferAllowlist (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerCount (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerReverseIndex (r:0 w:1)
+	fn burn_shares() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:2 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToDeposit (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToBurnedShares (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferExpiry (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferAllowlist (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToPriceFloor (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerCount (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToFrozen (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToName (r:0 w:1)
+	// Storage: ProportionalAssetModule Orders (r:0 w:1)
+	// Storage: ProportionalAssetModule OrderedShares (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToLocks (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerReverseIndex (r:0 w:1)
+	// Storage: ProportionalAssetModule Proposals (r:0 w:1)
+	// Storage: ProportionalAssetModule Bids (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToStatus (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetAllowance (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToTradeVolume (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToUri (r:0 w:1)
+	// Storage: ProportionalAssetModule MainOwnerToAssets (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToLastOffer (r:0 w:1)
+	fn destroy_asset() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(25 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferAllowlist (r:0 w:1)
+	fn set_offer_allowlist(a: u32, ) -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add((60_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToBurnedShares (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferExpiry (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferAllowlist (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerCount (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToFrozen (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerReverseIndex (r:0 w:2)
+	fn transfer_shares_batch(b: u32, ) -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add((9_000_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToPriceFloor (r:0 w:1)
+	fn set_price_floor() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToPriceFloor (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToFrozen (r:1 w:0)
+	// Storage: ProportionalAssetModule OrderedShares (r:1 w:0)
+	// Storage: ProportionalAssetModule PrivateOffers (r:1 w:0)
+	fn increase_offer() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:2 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToBurnedShares (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn distribute_dividend(o: u32, ) -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add((24_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(o as Weight)))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToBurnedShares (r:1 w:0)
+	// Storage: ProportionalAssetModule MainOwnerToAssets (r:0 w:2)
+	// Storage: ProportionalAssetModule ProportionalAssetToDeposit (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn transfer_main_ownership() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToFrozen (r:0 w:1)
+	fn set_frozen() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToFrozen (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToPriceFloor (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:1 w:0)
+	// Storage: ProportionalAssetModule OrderedShares (r:1 w:1)
+	// Storage: ProportionalAssetModule NextOrderId (r:1 w:1)
+	// Storage: ProportionalAssetModule Orders (r:0 w:1)
+	// Storage: ProportionalAssetModule PrivateOffers (r:1 w:0)
+	fn place_order() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: ProportionalAssetModule Orders (r:1 w:1)
+	// Storage: ProportionalAssetModule OrderedShares (r:1 w:1)
+	fn cancel_order() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToFrozen (r:1 w:0)
+	// Storage: ProportionalAssetModule Orders (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: ProportionalAssetModule OrderedShares (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerCount (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToBurnedShares (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerReverseIndex (r:0 w:2)
+	// Storage: ProportionalAssetModule ProportionalAssetToStatus (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToTradeVolume (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToPauseUntil (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn fill_order() -> Weight {
+		(55_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToLocks (r:1 w:1)
+	fn lock_shares() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToLocks (r:1 w:1)
+	fn unlock_shares() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:3 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:3 w:2)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferAllowlist (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferExpiry (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToBurnedShares (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerCount (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToFrozen (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerReverseIndex (r:0 w:2)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToStatus (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToTradeVolume (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToPauseUntil (r:1 w:0)
+	fn buyback_offered() -> Weight {
+		(54_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(17 as Weight))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:3 w:3)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:4 w:3)
+	// Storage: ProportionalAssetModule ProportionalAssetToDeposit (r:1 w:3)
+	// Storage: System Account (r:3 w:3)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerCount (r:3 w:3)
+	// Storage: ProportionalAssetModule ProportionalAssetToName (r:0 w:3)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerReverseIndex (r:0 w:3)
+	// Storage: ProportionalAssetModule ProportionalAssetToBurnedShares (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferExpiry (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferAllowlist (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToPriceFloor (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToFrozen (r:0 w:1)
+	// Storage: ProportionalAssetModule Orders (r:0 w:1)
+	// Storage: ProportionalAssetModule OrderedShares (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToLocks (r:0 w:1)
+	// Storage: ProportionalAssetModule Proposals (r:0 w:1)
+	// Storage: ProportionalAssetModule Bids (r:1 w:1)
+	fn split_asset() -> Weight {
+		(91_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(15 as Weight))
+			.saturating_add(T::DbWeight::get().writes(36 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:4 w:3)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:5 w:3)
+	// Storage: ProportionalAssetModule ProportionalAssetToDeposit (r:3 w:3)
+	// Storage: System Account (r:2 w:2)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerCount (r:2 w:3)
+	// Storage: ProportionalAssetModule ProportionalAssetToName (r:0 w:3)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerReverseIndex (r:0 w:3)
+	// Storage: ProportionalAssetModule ProportionalAssetToBurnedShares (r:0 w:2)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferExpiry (r:0 w:2)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferAllowlist (r:0 w:2)
+	// Storage: ProportionalAssetModule ProportionalAssetToPriceFloor (r:0 w:2)
+	// Storage: ProportionalAssetModule ProportionalAssetToFrozen (r:0 w:2)
+	// Storage: ProportionalAssetModule Orders (r:0 w:2)
+	// Storage: ProportionalAssetModule OrderedShares (r:0 w:2)
+	// Storage: ProportionalAssetModule ProportionalAssetToLocks (r:0 w:2)
+	// Storage: ProportionalAssetModule Proposals (r:0 w:2)
+	// Storage: ProportionalAssetModule Bids (r:2 w:2)
+	fn merge_assets() -> Weight {
+		(98_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(18 as Weight))
+			.saturating_add(T::DbWeight::get().writes(51 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:1 w:0)
+	// Storage: ProportionalAssetModule NextProposalId (r:1 w:1)
+	// Storage: ProportionalAssetModule Proposals (r:0 w:1)
+	// Storage: ProportionalAssetModule ProposalSnapshots (r:0 w:1)
+	// Storage: ProportionalAssetModule OpenProposals (r:1 w:1)
+	fn create_proposal(o: u32, ) -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add((2_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(o as Weight)))
+	}
+	// Storage: ProportionalAssetModule Proposals (r:1 w:1)
+	// Storage: ProportionalAssetModule ProposalVotes (r:1 w:1)
+	// Storage: ProportionalAssetModule ProposalSnapshots (r:1 w:1)
+	// Storage: ProportionalAssetModule ProposalTallies (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToBurnedShares (r:1 w:0)
+	// Storage: ProportionalAssetModule OpenProposals (r:1 w:1)
+	fn vote(v: u32, ) -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add((1_000_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(v as Weight)))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:1 w:0)
+	// Storage: ProportionalAssetModule Bids (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn make_bid() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:1 w:0)
+	// Storage: ProportionalAssetModule Bids (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToFrozen (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:2 w:2)
+	// Storage: ProportionalAssetModule ProportionalAssetToLocks (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerCount (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerReverseIndex (r:0 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: ProportionalAssetModule ProportionalAssetToStatus (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToTradeVolume (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToPauseUntil (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn accept_bid() -> Weight {
+		(48_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:1 w:0)
+	// Storage: ProportionalAssetModule Bids (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn cancel_bid() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToStatus (r:0 w:1)
+	fn set_status() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetAllowance (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:1 w:0)
+	fn approve_shares() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetAllowance (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:3 w:2)
+	// Storage: ProportionalAssetModule ProportionalAssetToBurnedShares (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferExpiry (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferAllowlist (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerCount (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToFrozen (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerReverseIndex (r:0 w:2)
+	fn transfer_shares_from() -> Weight {
+		(34_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToDeposit (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToBurnedShares (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferExpiry (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferAllowlist (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToPriceFloor (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerCount (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToFrozen (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToName (r:0 w:1)
+	// Storage: ProportionalAssetModule Orders (r:0 w:1)
+	// Storage: ProportionalAssetModule OrderedShares (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToLocks (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerReverseIndex (r:0 w:1)
+	// Storage: ProportionalAssetModule Proposals (r:0 w:1)
+	// Storage: ProportionalAssetModule Bids (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToStatus (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetAllowance (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToTradeVolume (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToUri (r:0 w:1)
+	fn force_destroy_asset(o: u32, ) -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add((2_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(21 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(o as Weight)))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToUri (r:0 w:1)
+	fn set_metadata_uri() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:2 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:2 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToBurnedShares (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToDeposit (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn recover_main_owner() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:2 w:0)
+	// Storage: ProportionalAssetModule SwapIntents (r:0 w:1)
+	fn register_swap_intent() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: ProportionalAssetModule SwapIntents (r:1 w:1)
+	fn cancel_swap_intent() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:2 w:2)
+	// Storage: ProportionalAssetModule SwapIntents (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:6 w:4)
+	// Storage: ProportionalAssetModule ProportionalAssetToBurnedShares (r:2 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferExpiry (r:0 w:2)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferAllowlist (r:0 w:2)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerCount (r:2 w:2)
+	// Storage: ProportionalAssetModule ProportionalAssetToFrozen (r:2 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerReverseIndex (r:0 w:4)
+	fn swap_shares() -> Weight {
+		(60_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().writes(16 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToFrozen (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToPriceFloor (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:1 w:0)
+	// Storage: ProportionalAssetModule PrivateOffers (r:1 w:1)
+	// Storage: ProportionalAssetModule OrderedShares (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToLocks (r:1 w:0)
+	fn offer_shares_to() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToFrozen (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToStatus (r:1 w:0)
+	// Storage: ProportionalAssetModule PrivateOffers (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:2 w:2)
+	// Storage: ProportionalAssetModule ProportionalAssetToLocks (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerCount (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerReverseIndex (r:0 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: ProportionalAssetModule ProportionalAssetToTradeVolume (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToPauseUntil (r:1 w:0)
+	fn buy_private() -> Weight {
+		(46_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:3 w:2)
+	// Storage: ProportionalAssetModule ProportionalAssetToLocks (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToBurnedShares (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferExpiry (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferAllowlist (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerCount (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToFrozen (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerReverseIndex (r:0 w:2)
+	fn transfer_all_shares() -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToPauseUntil (r:0 w:1)
+	fn schedule_pause() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:2 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToBurnedShares (r:1 w:0)
+	// Storage: ProportionalAssetModule MainOwnerToAssets (r:0 w:1)
+	fn reconcile_main_owner() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToFrozen (r:1 w:0)
+	// Storage: ProportionalAssetModule Auctions (r:2 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:1 w:0)
+	// Storage: ProportionalAssetModule OrderedShares (r:1 w:0)
+	// Storage: ProportionalAssetModule PrivateOffers (r:1 w:0)
+	// Storage: ProportionalAssetModule AuctionsEndingAt (r:0 w:1)
+	fn start_auction() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: ProportionalAssetModule Auctions (r:1 w:1)
+	// Storage: ProportionalAssetModule AuctionBids (r:2 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:1 w:0)
+	fn place_auction_bid() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: ProportionalAssetModule Auctions (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:2 w:2)
+	// Storage: ProportionalAssetModule ProportionalAssetToFrozen (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToBurnedShares (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerCount (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToTradeVolume (r:1 w:1)
+	// Storage: ProportionalAssetModule AuctionBids (r:1 w:1)
+	// Storage: System Account (r:1 w:2)
+	// Storage: ProportionalAssetModule ProportionalAssetToStatus (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToPauseUntil (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn settle_auction(b: u32, ) -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add((10_000_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(b as Weight)))
+	}
+	// Storage: ProportionalAssetModule ProportionalAssetToMainOwner (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerToMetadata (r:3 w:2)
+	// Storage: ProportionalAssetModule ProportionalAssetToBurnedShares (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferExpiry (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOfferAllowlist (r:0 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerCount (r:1 w:1)
+	// Storage: ProportionalAssetModule ProportionalAssetToFrozen (r:1 w:0)
+	// Storage: ProportionalAssetModule ProportionalAssetToOwnerReverseIndex (r:0 w:2)
+	// Storage: ProportionalAssetModule ProportionalAssetToLocks (r:1 w:1)
+	fn force_transfer_shares() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_proportional_asset() -> Weight {
+		(28_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+	fn offer_shares() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn transfer_shares_to_account() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	fn buy_shares() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(16 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
+	}
+	fn claim_onwership() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	fn burn_shares() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	fn destroy_asset() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(25 as Weight))
+	}
+	fn set_offer_allowlist(a: u32, ) -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add((60_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn transfer_shares_batch(b: u32, ) -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add((9_000_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	fn set_price_floor() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn increase_offer() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn distribute_dividend(o: u32, ) -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add((24_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(o as Weight)))
+	}
+	fn transfer_main_ownership() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	fn set_frozen() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn place_order() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn cancel_order() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn fill_order() -> Weight {
+		(55_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(11 as Weight))
+	}
+	fn lock_shares() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn unlock_shares() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn buyback_offered() -> Weight {
+		(54_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(17 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
+	}
+	fn split_asset() -> Weight {
+		(91_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(15 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(36 as Weight))
+	}
+	fn merge_assets() -> Weight {
+		(98_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(18 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(51 as Weight))
+	}
+	fn create_proposal(o: u32, ) -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add((2_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(o as Weight)))
+	}
+	fn vote(v: u32, ) -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add((1_000_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(v as Weight)))
+	}
+	fn make_bid() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn accept_bid() -> Weight {
+		(48_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+	fn cancel_bid() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn set_status() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn approve_shares() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn transfer_shares_from() -> Weight {
+		(34_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	fn force_destroy_asset(o: u32, ) -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add((2_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(21 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(o as Weight)))
+	}
+	fn set_metadata_uri() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn recover_main_owner() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn register_swap_intent() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn cancel_swap_intent() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn swap_shares() -> Weight {
+		(60_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(16 as Weight))
+	}
+	fn offer_shares_to() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn buy_private() -> Weight {
+		(46_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	fn transfer_all_shares() -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	fn schedule_pause() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn reconcile_main_owner() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn start_auction() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn place_auction_bid() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn settle_auction(b: u32, ) -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add((10_000_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(b as Weight)))
+	}
+	fn force_transfer_shares() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+}
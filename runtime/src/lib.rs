@@ -14,7 +14,10 @@ use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
-	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, Verify},
+	traits::{
+		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount,
+		NumberFor, Verify,
+	},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, MultiSignature,
 };
@@ -33,7 +36,7 @@ pub use frame_support::{
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_PER_SECOND},
 		IdentityFee, Weight,
 	},
-	StorageValue,
+	PalletId, StorageValue,
 };
 pub use frame_system::Call as SystemCall;
 pub use pallet_balances::Call as BalancesCall;
@@ -271,9 +274,51 @@ impl pallet_template::Config for Runtime {
 	type Event = Event;
 }
 
+parameter_types! {
+	pub const MarketplaceFee: Permill = Permill::zero();
+	// The price of the whole supply of 100 shares fits in the balance
+	pub const MaxSharePrice: Balance = Balance::MAX / 100;
+	pub const MaxPriceChange: Permill = Permill::zero();
+	pub const MaxHoldingPercent: Permill = Permill::one();
+	pub const MainOwnerExemptFromCap: bool = false;
+	pub const RoundingPolicy: pallet_proportional_asset::Rounding =
+		pallet_proportional_asset::Rounding::LargestRemainder;
+	pub const SaltedAssetIds: bool = false;
+	pub const ClearOffersOnTransfer: bool = false;
+	pub FeeCollector: AccountId = PalletId(*b"py/pafee").into_account_truncating();
+}
+
 impl pallet_proportional_asset::Config for Runtime {
 	type Event = Event;
 	type Currency = Balances;
+	type TotalSupply = ConstU64<100>;
+	type MaxDataLength = ConstU32<256>;
+	type AssetDeposit = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+	type MaxAllowlistLength = ConstU32<100>;
+	type WeightInfo = pallet_proportional_asset::weights::SubstrateWeight<Runtime>;
+	type MaxBatchSize = ConstU32<100>;
+	type MaxNameLength = ConstU32<64>;
+	type MaxUriLength = ConstU32<256>;
+	type MaxHolders = ConstU32<100>;
+	type MaxAuctionBidders = ConstU32<64>;
+	type MaxAuctionSettlements = ConstU32<16>;
+	type MinHolding = ConstU64<1>;
+	type MinShareTransfer = ConstU64<1>;
+	type MaxHoldingPercent = MaxHoldingPercent;
+	type MainOwnerExemptFromCap = MainOwnerExemptFromCap;
+	type RoundingPolicy = RoundingPolicy;
+	type SaltedAssetIds = SaltedAssetIds;
+	// Whole shares, more decimals would rescale the holdings of the existing assets
+	type ShareDecimals = ConstU32<0>;
+	type ClearOffersOnTransfer = ClearOffersOnTransfer;
+	type MarketplaceFee = MarketplaceFee;
+	type MaxSharePrice = MaxSharePrice;
+	type FeeCollector = FeeCollector;
+	type MaxPriceChange = MaxPriceChange;
+	type MinReofferInterval = ConstU32<0>;
+	type OnMainOwnerChanged = ();
+	type MaxPriceHistory = ConstU32<100>;
+	type MaxOpenProposals = ConstU32<16>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -326,8 +371,17 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
+/// The storage migrations applied on runtime upgrades.
+type Migrations = (
+	pallet_proportional_asset::migrations::v1::MigrateToV1<Runtime>,
+	pallet_proportional_asset::migrations::v2::MigrateToV2<Runtime>,
+	pallet_proportional_asset::migrations::v3::MigrateToV3<Runtime>,
+	pallet_proportional_asset::migrations::v4::MigrateToV4<Runtime>,
+);
+
 #[cfg(feature = "runtime-benchmarks")]
 #[macro_use]
 extern crate frame_benchmarking;
@@ -492,6 +546,42 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_proportional_asset_runtime_api::ProportionalAssetApi<Block, AccountId, Hash, Balance>
+		for Runtime
+	{
+		fn owners_of(id: Hash) -> Vec<(AccountId, pallet_proportional_asset::MetaData<Balance>)> {
+			ProportionalAssetModule::owners_of(&id)
+		}
+
+		fn owners_of_paged(
+			id: Hash,
+			start_key: Option<AccountId>,
+			limit: u32,
+		) -> (Vec<(AccountId, pallet_proportional_asset::MetaData<Balance>)>, Option<AccountId>) {
+			ProportionalAssetModule::owners_of_paged(&id, start_key, limit)
+		}
+
+		fn asset_info(id: Hash) -> Option<pallet_proportional_asset::AssetInfo<AccountId>> {
+			ProportionalAssetModule::asset_info(&id)
+		}
+
+		fn quote_buy(id: Hash, buyer: AccountId, from: AccountId, shares: u64) -> Option<Balance> {
+			ProportionalAssetModule::quote_buy(&id, &buyer, &from, shares).ok()
+		}
+
+		fn active_offer(id: Hash, who: AccountId) -> Option<(u64, Balance)> {
+			ProportionalAssetModule::active_offer(&id, &who)
+		}
+
+		fn market_depth(id: Hash) -> (u64, Option<Balance>) {
+			ProportionalAssetModule::market_depth(&id)
+		}
+
+		fn can_claim_ownership(id: Hash, who: AccountId) -> bool {
+			ProportionalAssetModule::can_claim_ownership(&id, &who)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -0,0 +1,20 @@
+#!/usr/bin/env bash
+# This script is meant to be run on Unix/Linux based systems
+# It regenerates the weights of pallet_proportional_asset from its benchmarks
+set -e
+
+echo "*** Building the node with the runtime benchmarks"
+
+cargo build --release -p node-template --features runtime-benchmarks
+
+echo "*** Benchmarking pallet_proportional_asset"
+
+./target/release/node-template benchmark pallet \
+	--chain dev \
+	--execution wasm \
+	--wasm-execution compiled \
+	--pallet pallet_proportional_asset \
+	--extrinsic '*' \
+	--steps 50 \
+	--repeat 20 \
+	--output pallets/proportional-asset/src/weights.rs